@@ -0,0 +1,43 @@
+use actix_web::{web, HttpResponse, Scope};
+use usecase::interactors::InteractorContainer;
+
+use super::HandlerReturnType;
+use infrastructure::postgres::PlainAuditLog;
+use usecase::interactors::audit::{AuditLogInteractor, AuditLogListInput};
+
+pub fn audit_router<C>() -> Scope
+where
+    C: InteractorContainer,
+{
+    web::scope("/api/audit").route("", web::get().to(find_all::<C>))
+}
+
+/// 検索条件に一致する監査ログを、記録した日時の新しい順に検索するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/audit?entity=vegetable&entityId={id}&limit=100
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `query` - 監査ログの検索条件
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_all<C>(
+    repo_container: web::Data<C>,
+    query: web::Query<AuditLogListInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let logs: Vec<PlainAuditLog> = repo_container
+        .audit()
+        .find_all(query.into_inner())
+        .await?
+        .into_iter()
+        .map(|l| l.into())
+        .collect();
+
+    Ok(HttpResponse::Ok().json(logs))
+}
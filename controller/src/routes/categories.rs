@@ -0,0 +1,160 @@
+use actix_web::{web, HttpResponse, Scope};
+use usecase::interactors::InteractorContainer;
+
+use super::{ControllerError, HandlerReturnType};
+use infrastructure::postgres::PlainCategory;
+use usecase::interactors::category::{CategoryInteractor, UpsertCategoryInput};
+
+pub fn category_router<C>() -> Scope
+where
+    C: InteractorContainer,
+{
+    web::scope("/api/categories")
+        .route("", web::get().to(find_all::<C>))
+        .route("", web::post().to(register::<C>))
+        .route("/{id}", web::get().to(find_by_id::<C>))
+        .route("/{id}", web::put().to(update::<C>))
+        .route("/{id}", web::delete().to(delete::<C>))
+}
+
+/// すべてのカテゴリを検索するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/categories
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_all<C>(repo_container: web::Data<C>) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let categories: Vec<PlainCategory> = repo_container
+        .category()
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|c| c.into())
+        .collect();
+
+    Ok(HttpResponse::Ok().json(categories))
+}
+
+/// カテゴリをIDで検索するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/categories/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - カテゴリID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_by_id<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let category: PlainCategory = repo_container
+        .category()
+        .find_by_id(&path.into_inner().0)
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into();
+
+    Ok(HttpResponse::Ok().json(category))
+}
+
+/// カテゴリを登録するハンドラ関数
+///
+/// [POST] http://localhost:8001/api/categories
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `category` - カテゴリ
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn register<C>(
+    repo_container: web::Data<C>,
+    category: web::Json<UpsertCategoryInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let category: PlainCategory = repo_container
+        .category()
+        .register(category.into_inner())
+        .await?
+        .into();
+
+    Ok(HttpResponse::Ok().json(category))
+}
+
+/// カテゴリを更新するハンドラ関数
+///
+/// [PUT] http://localhost:8001/api/categories/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - カテゴリID
+/// * `category` - カテゴリ
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn update<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+    category: web::Json<UpsertCategoryInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let category: PlainCategory = repo_container
+        .category()
+        .update(&path.into_inner().0, category.into_inner())
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into();
+
+    Ok(HttpResponse::Ok().json(category))
+}
+
+/// カテゴリを削除するハンドラ関数
+///
+/// 削除したカテゴリに分類されていた野菜は、カテゴリに分類されていない野菜になる。
+///
+/// [DELETE] http://localhost:8001/api/categories/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - カテゴリID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn delete<C>(repo_container: web::Data<C>, path: web::Path<(String,)>) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    match repo_container
+        .category()
+        .delete(path.into_inner().0.as_str())
+        .await?
+    {
+        0 => Err(ControllerError::NotFound),
+        _ => Ok(HttpResponse::Ok().finish()),
+    }
+}
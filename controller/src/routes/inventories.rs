@@ -0,0 +1,167 @@
+use actix_web::{web, HttpResponse, Scope};
+use usecase::interactors::InteractorContainer;
+
+use super::{ControllerError, HandlerReturnType};
+use infrastructure::postgres::PlainInventory;
+use usecase::interactors::inventory::{InventoryInteractor, InventoryQuantityInput};
+
+pub fn inventory_router<C>() -> Scope
+where
+    C: InteractorContainer,
+{
+    web::scope("/api/inventories")
+        .route("", web::get().to(find_all::<C>))
+        .route("/{id}", web::get().to(find_by_vegetable_id::<C>))
+        .route("/{id}", web::delete().to(delete::<C>))
+        .route("/{id}/receipts", web::post().to(receive::<C>))
+        .route("/{id}/disposals", web::post().to(dispose::<C>))
+}
+
+/// 在庫を管理しているすべての野菜の在庫を検索するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/inventories
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_all<C>(repo_container: web::Data<C>) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let inventories: Vec<PlainInventory> = repo_container
+        .inventory()
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|i| i.into())
+        .collect();
+
+    Ok(HttpResponse::Ok().json(inventories))
+}
+
+/// 野菜の在庫を野菜IDで検索するハンドラ関数
+///
+/// 在庫を管理していない野菜の場合は`404 Not Found`を返す。
+///
+/// [GET] http://localhost:8001/api/inventories/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_by_vegetable_id<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let inventory: PlainInventory = repo_container
+        .inventory()
+        .find_by_vegetable_id(&path.into_inner().0)
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into();
+
+    Ok(HttpResponse::Ok().json(inventory))
+}
+
+/// 野菜を入荷するハンドラ関数
+///
+/// 在庫を管理していない野菜の場合は、在庫の管理を開始する。
+///
+/// [POST] http://localhost:8001/api/inventories/{id}/receipts
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - 野菜ID
+/// * `input` - 入荷した数量
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn receive<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+    input: web::Json<InventoryQuantityInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let inventory: PlainInventory = repo_container
+        .inventory()
+        .receive(&path.into_inner().0, input.into_inner())
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into();
+
+    Ok(HttpResponse::Ok().json(inventory))
+}
+
+/// 傷んだ野菜などを廃棄するハンドラ関数
+///
+/// [POST] http://localhost:8001/api/inventories/{id}/disposals
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - 野菜ID
+/// * `input` - 廃棄する数量
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn dispose<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+    input: web::Json<InventoryQuantityInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let inventory: PlainInventory = repo_container
+        .inventory()
+        .dispose(&path.into_inner().0, input.into_inner())
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into();
+
+    Ok(HttpResponse::Ok().json(inventory))
+}
+
+/// 野菜の在庫の管理を終了するハンドラ関数
+///
+/// 在庫の管理を終了した野菜は、在庫が無限にあるものとして販売する。
+///
+/// [DELETE] http://localhost:8001/api/inventories/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn delete<C>(repo_container: web::Data<C>, path: web::Path<(String,)>) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    match repo_container
+        .inventory()
+        .delete(path.into_inner().0.as_str())
+        .await?
+    {
+        0 => Err(ControllerError::NotFound),
+        _ => Ok(HttpResponse::Ok().finish()),
+    }
+}
@@ -1,43 +1,148 @@
-use std::fmt::{Debug, Display};
+use actix_web::http::StatusCode;
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
 
-use actix_web::HttpResponse;
+use domain::models::primitives::Actor;
+use usecase::UsecaseError;
 
+pub mod audit;
+pub mod categories;
+pub mod inventories;
+pub mod reports;
+pub mod sales;
 pub mod vegetables;
 
-pub type HandlerReturnType = Result<HttpResponse, actix_web::error::Error>;
+pub type HandlerReturnType = Result<HttpResponse, ControllerError>;
 
-/// 400 Bad Request Errorを作成する。
+/// 野菜や販売を変更する利用者を指定するヘッダの名前
+pub const ACTOR_HEADER: &str = "X-Actor";
+
+/// コントローラーエラー
+#[derive(thiserror::Error, Debug)]
+pub enum ControllerError {
+    /// リクエストの形式が正しくない
+    #[error("リクエストの形式が正しくありません。")]
+    BadRequest(String),
+
+    /// リソースが見つからない
+    #[error("リソースが見つかりませんでした。")]
+    NotFound,
+
+    /// 更新の前提条件が指定されていない
+    #[error("If-Matchヘッダにリソースのエンティティタグを指定してください。")]
+    PreconditionRequired,
+
+    /// ユースケースエラー
+    #[error(transparent)]
+    Usecase(#[from] UsecaseError),
+}
+
+/// エラーレスポンスのボディ
+#[derive(serde::Serialize)]
+struct ErrorResponseBody {
+    /// エラーコード
+    code: &'static str,
+    /// エラーメッセージ
+    message: String,
+    /// エラーの詳細
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<String>,
+}
+
+impl ControllerError {
+    /// エラーコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// エラーコード
+    fn code(&self) -> &'static str {
+        match self {
+            Self::BadRequest(_) => "BAD_REQUEST",
+            Self::NotFound => "NOT_FOUND",
+            Self::PreconditionRequired => "PRECONDITION_REQUIRED",
+            Self::Usecase(UsecaseError::Validation(_)) => "VALIDATION_ERROR",
+            Self::Usecase(UsecaseError::DomainRule(_)) => "DOMAIN_RULE_VIOLATION",
+            Self::Usecase(UsecaseError::Conflict(_)) => "PRECONDITION_FAILED",
+            Self::Usecase(UsecaseError::Unexpected(_)) => "INTERNAL_SERVER_ERROR",
+        }
+    }
+}
+
+impl ResponseError for ControllerError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::PreconditionRequired => StatusCode::PRECONDITION_REQUIRED,
+            Self::Usecase(UsecaseError::Validation(_)) => StatusCode::BAD_REQUEST,
+            Self::Usecase(UsecaseError::DomainRule(_)) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Usecase(UsecaseError::Conflict(_)) => StatusCode::PRECONDITION_FAILED,
+            Self::Usecase(UsecaseError::Unexpected(_)) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let (message, details) = match self {
+            Self::BadRequest(details) => (self.to_string(), Some(details.clone())),
+            Self::NotFound | Self::PreconditionRequired => (self.to_string(), None),
+            Self::Usecase(UsecaseError::Validation(message))
+            | Self::Usecase(UsecaseError::DomainRule(message))
+            | Self::Usecase(UsecaseError::Conflict(message)) => (message.to_string(), None),
+            Self::Usecase(UsecaseError::Unexpected(e)) => {
+                // 内部のエラーはログに記録して、クライアントには返さない
+                log::error!("{:?}", e);
+                ("予期しないエラーが発生しました。".to_string(), None)
+            }
+        };
+
+        HttpResponse::build(self.status_code()).json(ErrorResponseBody {
+            code: self.code(),
+            message,
+            details,
+        })
+    }
+}
+
+/// リクエストボディやクエリ文字列を抽出できなかったときのエラーを、
+/// コントローラーエラーに変換する。
+///
+/// `web::JsonConfig`や`web::QueryConfig`のエラーハンドラに登録する。
 ///
 /// # 引数
 ///
-/// * `err` - エラー
+/// * `err` - 抽出したときに発生したエラー
+/// * `_req` - リクエスト
 ///
 /// # 戻り値
 ///
-/// `actix_web::error::ErrorBadRequest`
-pub fn e400<E>(err: E) -> actix_web::Error
+/// `ControllerError::BadRequest`
+pub fn payload_error_handler<E>(err: E, _req: &HttpRequest) -> actix_web::Error
 where
-    E: Debug + Display + 'static,
+    E: std::fmt::Display,
 {
-    actix_web::error::ErrorBadRequest(err)
-}
-
-pub fn e404() -> actix_web::Error {
-    actix_web::error::ErrorNotFound("リソースが見つかりませんでした。")
+    ControllerError::BadRequest(err.to_string()).into()
 }
 
-/// 500 Internal Server Errorを作成する。
+/// `X-Actor`ヘッダから、野菜や販売を変更する利用者を取得する。
 ///
 /// # 引数
 ///
-/// * `err` - エラー
+/// * `req` - リクエスト
 ///
 /// # 戻り値
 ///
-/// `actix_web::error::ErrorBadRequest`
-pub fn e500<E>(err: E) -> actix_web::Error
-where
-    E: Debug + Display + 'static,
-{
-    actix_web::error::ErrorInternalServerError(err)
+/// 利用者（`X-Actor`ヘッダが指定されていない場合は`anonymous`）
+///
+/// # エラー
+///
+/// * `ControllerError::BadRequest` - `X-Actor`ヘッダに表示可能なASCII文字以外が含まれる場合
+pub fn request_actor(req: &HttpRequest) -> Result<&str, ControllerError> {
+    match req.headers().get(ACTOR_HEADER) {
+        Some(value) => value.to_str().map_err(|_| {
+            ControllerError::BadRequest(format!(
+                "{}ヘッダは表示可能なASCII文字で指定してください。",
+                ACTOR_HEADER
+            ))
+        }),
+        None => Ok(Actor::ANONYMOUS),
+    }
 }
@@ -0,0 +1,39 @@
+use actix_web::{web, HttpResponse, Scope};
+use usecase::interactors::InteractorContainer;
+
+use super::HandlerReturnType;
+use usecase::interactors::report::{DailySalesInput, ReportInteractor};
+
+pub fn report_router<C>() -> Scope
+where
+    C: InteractorContainer,
+{
+    web::scope("/api/reports").route("/daily", web::get().to(daily_sales::<C>))
+}
+
+/// 指定した日の売上を集計するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/reports/daily?date=YYYY-MM-DD
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `query` - 日次売上集計の条件
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn daily_sales<C>(
+    repo_container: web::Data<C>,
+    query: web::Query<DailySalesInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let summary = repo_container
+        .report()
+        .daily_sales(query.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(summary))
+}
@@ -0,0 +1,181 @@
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use usecase::interactors::InteractorContainer;
+
+use super::{request_actor, ControllerError, HandlerReturnType};
+use domain::models::sales::ReturnedSale;
+use infrastructure::postgres::PlainSale;
+use usecase::interactors::sale::{PurchaseInput, ReturnItemInput, SaleInteractor};
+
+pub fn sale_router<C>() -> Scope
+where
+    C: InteractorContainer,
+{
+    web::scope("/api/sales")
+        .route("", web::get().to(find_all::<C>))
+        .route("", web::post().to(purchase::<C>))
+        .route("/{id}", web::get().to(find_by_id::<C>))
+        .route("/{id}", web::delete().to(cancel::<C>))
+        .route("/{id}/returns", web::patch().to(return_item::<C>))
+}
+
+/// 販売をすべて検索するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/sales
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_all<C>(repo_container: web::Data<C>) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let sales: Vec<PlainSale> = repo_container
+        .sale()
+        .find_all()
+        .await?
+        .into_iter()
+        .map(|s| s.into())
+        .collect();
+
+    Ok(HttpResponse::Ok().json(sales))
+}
+
+/// 販売をIDで検索するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/sales/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - 販売ID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_by_id<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let sale = repo_container
+        .sale()
+        .find_by_id(&path.into_inner().0)
+        .await?;
+    if sale.is_none() {
+        return Err(ControllerError::NotFound);
+    }
+    let sale: PlainSale = sale.unwrap().into();
+
+    Ok(HttpResponse::Ok().json(sale))
+}
+
+/// 代金を支払い野菜を購入するハンドラ関数
+///
+/// [POST] http://localhost:8001/api/sales
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
+/// * `purchase` - 購入する野菜と数量
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn purchase<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
+    purchase: web::Json<PurchaseInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let sale = repo_container
+        .sale()
+        .purchase(purchase.into_inner(), request_actor(&req)?)
+        .await?;
+    let sale: PlainSale = sale.into();
+
+    Ok(HttpResponse::Ok().json(sale))
+}
+
+/// 購入した野菜のうち、いずれかの野菜を返品するハンドラ関数
+///
+/// 販売明細が残っている場合は返品した後の販売を返し、購入した野菜がすべて返品された
+/// 場合は販売を削除して`204 No Content`を返す。
+///
+/// [PATCH] http://localhost:8001/api/sales/{id}/returns
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
+/// * `id` - 販売ID
+/// * `item` - 返品する野菜と数量
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn return_item<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
+    path: web::Path<(String,)>,
+    item: web::Json<ReturnItemInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    match repo_container
+        .sale()
+        .return_item(
+            &path.into_inner().0,
+            item.into_inner(),
+            request_actor(&req)?,
+        )
+        .await?
+    {
+        Some(ReturnedSale::Remaining(sale)) => {
+            let sale: PlainSale = sale.into();
+            Ok(HttpResponse::Ok().json(sale))
+        }
+        Some(ReturnedSale::FullyReturned(_)) => Ok(HttpResponse::NoContent().finish()),
+        None => Err(ControllerError::NotFound),
+    }
+}
+
+/// 購入した野菜をすべて返品して、購入をキャンセルするハンドラ関数
+///
+/// [DELETE] http://localhost:8001/api/sales/{id}
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
+/// * `id` - 販売ID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn cancel<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
+    path: web::Path<(String,)>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    match repo_container
+        .sale()
+        .cancel(path.into_inner().0.as_str(), request_actor(&req)?)
+        .await?
+    {
+        0 => Err(ControllerError::NotFound),
+        _ => Ok(HttpResponse::Ok().finish()),
+    }
+}
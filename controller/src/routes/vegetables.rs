@@ -1,50 +1,55 @@
-use actix_web::{web, HttpResponse, Scope};
-use usecase::interactors::UsecaseInteractorContainer;
+use actix_web::http::header::{EntityTag, Header, IfMatch, ETAG, IF_MATCH};
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use usecase::interactors::InteractorContainer;
 
-use super::{e404, e500, HandlerReturnType};
-use infrastructure::postgres::PlainVegetable;
+use super::{request_actor, ControllerError, HandlerReturnType};
+use domain::models::vegetable::Vegetable;
+use infrastructure::postgres::{PlainVegetable, PlainVegetablePage, PlainVegetablePrice};
 use usecase::interactors::vegetable::{
-    PartialVegetableInput, UpsertVegetableInput, VegetableInteractor,
+    PartialVegetableInput, UpsertVegetableInput, VegetableDeleteInput, VegetableInteractor,
+    VegetableListInput, VegetablePriceInput,
 };
 
-pub fn vegetable_router<VI>() -> Scope
+pub fn vegetable_router<C>() -> Scope
 where
-    VI: VegetableInteractor + 'static,
+    C: InteractorContainer,
 {
     web::scope("/api/vegetables")
-        .route("", web::get().to(find_all::<VI>))
-        .route("", web::post().to(register::<VI>))
-        .route("/{id}", web::get().to(find_by_id::<VI>))
-        .route("/{id}", web::put().to(update::<VI>))
-        .route("/{id}", web::patch().to(partial_update::<VI>))
-        .route("/{id}", web::delete().to(delete::<VI>))
+        .route("", web::get().to(find_all::<C>))
+        .route("", web::post().to(register::<C>))
+        .route("/{id}", web::get().to(find_by_id::<C>))
+        .route("/{id}", web::put().to(update::<C>))
+        .route("/{id}", web::patch().to(partial_update::<C>))
+        .route("/{id}", web::delete().to(delete::<C>))
+        .route("/{id}/restore", web::post().to(restore::<C>))
+        .route("/{id}/prices", web::get().to(find_prices::<C>))
+        .route("/{id}/prices", web::post().to(register_price::<C>))
 }
 
-/// 野菜をすべて検索するハンドラ関数
+/// 検索条件に一致する野菜を検索するハンドラ関数
 ///
-/// [GET] http://localhost:8001/api/vegetables
+/// [GET] http://localhost:8001/api/vegetables?page=1&limit=20&name=ト&minUnitPrice=50&maxUnitPrice=200&sort=unitPrice&order=desc
 ///
 /// # 引数
 ///
 /// * `repo_container` - リポジトリコンテナ
+/// * `query` - 野菜の検索条件
 ///
 /// # 戻り値
 ///
 /// レスポンス
-async fn find_all<VI>(
-    repo_container: web::Data<UsecaseInteractorContainer<VI>>,
+async fn find_all<C>(
+    repo_container: web::Data<C>,
+    query: web::Query<VegetableListInput>,
 ) -> HandlerReturnType
 where
-    VI: VegetableInteractor,
+    C: InteractorContainer,
 {
-    let vegetables: Vec<PlainVegetable> = repo_container
-        .vegetable
-        .find_all()
-        .await
-        .map_err(e500)?
-        .into_iter()
-        .map(|v| v.into())
-        .collect();
+    let vegetables: PlainVegetablePage = repo_container
+        .vegetable()
+        .find_all(query.into_inner())
+        .await?
+        .into();
 
     Ok(HttpResponse::Ok().json(vegetables))
 }
@@ -56,28 +61,27 @@ where
 /// # 引数
 ///
 /// * `repo_container` - リポジトリコンテナ
+/// * `id` - 野菜ID
 ///
 /// # 戻り値
 ///
-/// レスポンス
-async fn find_by_id<VI>(
-    repo_container: web::Data<UsecaseInteractorContainer<VI>>,
+/// 野菜の版をエンティティタグとして`ETag`ヘッダに設定したレスポンス
+async fn find_by_id<C>(
+    repo_container: web::Data<C>,
     path: web::Path<(String,)>,
 ) -> HandlerReturnType
 where
-    VI: VegetableInteractor,
+    C: InteractorContainer,
 {
     let vegetable = repo_container
-        .vegetable
+        .vegetable()
         .find_by_id(&path.into_inner().0)
-        .await
-        .map_err(e500)?;
+        .await?;
     if vegetable.is_none() {
-        return Err(e404());
+        return Err(ControllerError::NotFound);
     }
-    let vegetable: PlainVegetable = vegetable.unwrap().into();
 
-    Ok(HttpResponse::Ok().json(vegetable))
+    Ok(vegetable_response(vegetable.unwrap()))
 }
 
 /// 野菜を登録するハンドラ関数
@@ -87,117 +91,645 @@ where
 /// # 引数
 ///
 /// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
 /// * `vegetable` - 野菜
 ///
 /// # 戻り値
 ///
 /// レスポンス
-async fn register<VI>(
-    repo_container: web::Data<UsecaseInteractorContainer<VI>>,
+async fn register<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
     vegetable: web::Json<UpsertVegetableInput>,
 ) -> HandlerReturnType
 where
-    VI: VegetableInteractor,
+    C: InteractorContainer,
 {
     let vegetable = repo_container
-        .vegetable
-        .register(vegetable.into_inner())
-        .await
-        .map_err(e500)?;
-    let vegetable: PlainVegetable = vegetable.into();
+        .vegetable()
+        .register(vegetable.into_inner(), request_actor(&req)?)
+        .await?;
 
-    Ok(HttpResponse::Ok().json(vegetable))
+    Ok(vegetable_response(vegetable))
 }
 
 /// 野菜を更新するハンドラ関数
 ///
+/// 更新する野菜の版を、`If-Match`ヘッダにエンティティタグとして指定する必要がある。
+///
 /// [PUT] http://localhost:8001/api/vegetables/{id}
 ///
 ///
 /// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
 /// * `vegetable` - 野菜
 ///
 /// # 戻り値
 ///
 /// レスポンス
-async fn update<VI>(
-    repo_container: web::Data<UsecaseInteractorContainer<VI>>,
+async fn update<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
     path: web::Path<(String,)>,
     vegetable: web::Json<UpsertVegetableInput>,
 ) -> HandlerReturnType
 where
-    VI: VegetableInteractor,
+    C: InteractorContainer,
 {
     let vegetable = repo_container
-        .vegetable
-        .update(&path.into_inner().0, vegetable.into_inner())
-        .await
-        .map_err(e500)?;
+        .vegetable()
+        .update(
+            &path.into_inner().0,
+            if_match_version(&req)?,
+            vegetable.into_inner(),
+            request_actor(&req)?,
+        )
+        .await?;
     if vegetable.is_none() {
-        return Err(e404());
+        return Err(ControllerError::NotFound);
     }
-    let vegetable: PlainVegetable = vegetable.unwrap().into();
 
-    Ok(HttpResponse::Ok().json(vegetable))
+    Ok(vegetable_response(vegetable.unwrap()))
 }
 
 /// 野菜を部分更新するハンドラ関数
 ///
+/// 部分更新する野菜の版を、`If-Match`ヘッダにエンティティタグとして指定する必要がある。
+///
 /// [PATCH] http://localhost:8001/api/vegetables/{id}
 ///
 ///
 /// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
 /// * `vegetable` - 野菜
 ///
 /// # 戻り値
 ///
 /// レスポンス
-async fn partial_update<VI>(
-    repo_container: web::Data<UsecaseInteractorContainer<VI>>,
+async fn partial_update<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
     path: web::Path<(String,)>,
     vegetable: web::Json<PartialVegetableInput>,
 ) -> HandlerReturnType
 where
-    VI: VegetableInteractor,
+    C: InteractorContainer,
 {
     let vegetable = repo_container
-        .vegetable
-        .partial_update(&path.into_inner().0, vegetable.into_inner())
-        .await
-        .map_err(e500)?;
+        .vegetable()
+        .partial_update(
+            &path.into_inner().0,
+            if_match_version(&req)?,
+            vegetable.into_inner(),
+            request_actor(&req)?,
+        )
+        .await?;
     if vegetable.is_none() {
-        return Err(e404());
+        return Err(ControllerError::NotFound);
     }
-    let vegetable: PlainVegetable = vegetable.unwrap().into();
 
-    Ok(HttpResponse::Ok().json(vegetable))
+    Ok(vegetable_response(vegetable.unwrap()))
 }
 
 /// 野菜をIDを指定して削除する関数
 ///
-/// [DELETE] http://localhost:8001/api/vegetables/{id}
+/// `permanent=true`が指定されていない場合は、野菜を論理削除する。削除する野菜の版を、
+/// `If-Match`ヘッダにエンティティタグとして指定する必要がある。
+///
+/// [DELETE] http://localhost:8001/api/vegetables/{id}?permanent=true
 ///
 ///
 /// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
 /// * `id` - 野菜ID
+/// * `query` - 野菜の削除方法
 ///
 /// # 戻り値
 ///
 /// レスポンス
-async fn delete<VI>(
-    repo_container: web::Data<UsecaseInteractorContainer<VI>>,
+async fn delete<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
     path: web::Path<(String,)>,
+    query: web::Query<VegetableDeleteInput>,
 ) -> HandlerReturnType
 where
-    VI: VegetableInteractor,
+    C: InteractorContainer,
 {
     match repo_container
-        .vegetable
-        .delete(path.into_inner().0.as_str())
-        .await
-        .map_err(e500)?
+        .vegetable()
+        .delete(
+            path.into_inner().0.as_str(),
+            if_match_version(&req)?,
+            query.into_inner(),
+            request_actor(&req)?,
+        )
+        .await?
     {
-        0 => Err(e404()),
+        0 => Err(ControllerError::NotFound),
         _ => Ok(HttpResponse::Ok().finish()),
     }
 }
+
+/// 論理削除した野菜を復元するハンドラ関数
+///
+/// [POST] http://localhost:8001/api/vegetables/{id}/restore
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn restore<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
+    path: web::Path<(String,)>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let vegetable = repo_container
+        .vegetable()
+        .restore(&path.into_inner().0, request_actor(&req)?)
+        .await?
+        .ok_or(ControllerError::NotFound)?;
+
+    Ok(vegetable_response(vegetable))
+}
+
+/// 野菜の価格の履歴を取得するハンドラ関数
+///
+/// [GET] http://localhost:8001/api/vegetables/{id}/prices
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn find_prices<C>(
+    repo_container: web::Data<C>,
+    path: web::Path<(String,)>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let prices: Vec<PlainVegetablePrice> = repo_container
+        .vegetable()
+        .find_prices(&path.into_inner().0)
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into_iter()
+        .map(|p| p.into())
+        .collect();
+
+    Ok(HttpResponse::Ok().json(prices))
+}
+
+/// 野菜に現在以降に適用する価格を登録するハンドラ関数
+///
+/// [POST] http://localhost:8001/api/vegetables/{id}/prices
+///
+/// # 引数
+///
+/// * `repo_container` - リポジトリコンテナ
+/// * `req` - リクエスト
+/// * `id` - 野菜ID
+/// * `price` - 野菜の価格
+///
+/// # 戻り値
+///
+/// レスポンス
+async fn register_price<C>(
+    repo_container: web::Data<C>,
+    req: HttpRequest,
+    path: web::Path<(String,)>,
+    price: web::Json<VegetablePriceInput>,
+) -> HandlerReturnType
+where
+    C: InteractorContainer,
+{
+    let price: PlainVegetablePrice = repo_container
+        .vegetable()
+        .register_price(
+            &path.into_inner().0,
+            price.into_inner(),
+            request_actor(&req)?,
+        )
+        .await?
+        .ok_or(ControllerError::NotFound)?
+        .into();
+
+    Ok(HttpResponse::Ok().json(price))
+}
+
+/// 野菜の版をエンティティタグとして`ETag`ヘッダに設定した、野菜を返すレスポンスを構築する。
+///
+/// # 引数
+///
+/// * `vegetable` - 野菜
+///
+/// # 戻り値
+///
+/// レスポンス
+fn vegetable_response(vegetable: Vegetable) -> HttpResponse {
+    let etag = EntityTag::new_strong(vegetable.version().to_string());
+    let vegetable: PlainVegetable = vegetable.into();
+
+    HttpResponse::Ok()
+        .insert_header((ETAG, etag.to_string()))
+        .json(vegetable)
+}
+
+/// `If-Match`ヘッダから、更新または削除する野菜の版を取得する。
+///
+/// # 引数
+///
+/// * `req` - リクエスト
+///
+/// # 戻り値
+///
+/// 野菜の版（`If-Match: *`が指定された場合は`None`）
+///
+/// # エラー
+///
+/// * `ControllerError::PreconditionRequired` - `If-Match`ヘッダが指定されていない場合
+/// * `ControllerError::BadRequest` - `If-Match`ヘッダに、野菜の版を表す強いエンティティ
+///   タグが1つ指定されていない場合
+fn if_match_version(req: &HttpRequest) -> Result<Option<u32>, ControllerError> {
+    if !req.headers().contains_key(IF_MATCH) {
+        return Err(ControllerError::PreconditionRequired);
+    }
+    let bad_request = || {
+        ControllerError::BadRequest(
+            "If-Matchヘッダには、野菜の版を表す強いエンティティタグを1つ指定してください。"
+                .to_string(),
+        )
+    };
+    match IfMatch::parse(req).map_err(|_| bad_request())? {
+        IfMatch::Any => Ok(None),
+        IfMatch::Items(tags) => match tags.as_slice() {
+            [tag] if !tag.weak => tag.tag().parse().map(Some).map_err(|_| bad_request()),
+            _ => Err(bad_request()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::MessageBody;
+    use actix_web::dev::{ServiceFactory, ServiceRequest, ServiceResponse};
+    use actix_web::http::StatusCode;
+    use actix_web::test::{self, TestRequest};
+    use actix_web::App;
+    use async_trait::async_trait;
+    use time::UtcOffset;
+
+    use super::*;
+    use crate::routes::payload_error_handler;
+    use domain::models::audit::AuditLog;
+    use domain::models::category::Category;
+    use domain::models::inventory::Inventory;
+    use domain::models::sales::{ReturnedSale, Sale};
+    use infrastructure::memory::interactors::vegetable::MemoryVegetableInteractor;
+    use infrastructure::memory::repositories::vegetable::MemoryVegetableRepository;
+    use usecase::interactors::audit::{AuditLogInteractor, AuditLogListInput};
+    use usecase::interactors::category::{CategoryInteractor, UpsertCategoryInput};
+    use usecase::interactors::inventory::{InventoryInteractor, InventoryQuantityInput};
+    use usecase::interactors::report::{DailySalesInput, DailySalesSummary, ReportInteractor};
+    use usecase::interactors::sale::{PurchaseInput, ReturnItemInput, SaleInteractor};
+    use usecase::interactors::UsecaseInteractorContainer;
+    use usecase::{UsecaseError, UsecaseResult};
+
+    /// 野菜のルートが使用しない、販売、売上、在庫、カテゴリ及び監査ログのインタラクター
+    #[derive(Clone)]
+    struct UnusedInteractor;
+
+    fn unused<T>() -> UsecaseResult<T> {
+        Err(UsecaseError::Unexpected(anyhow::anyhow!(
+            "野菜のルートは、野菜インタラクター以外を使用しません。"
+        )))
+    }
+
+    #[async_trait]
+    impl SaleInteractor for UnusedInteractor {
+        async fn find_by_id(&self, _: &str) -> UsecaseResult<Option<Sale>> {
+            unused()
+        }
+
+        async fn find_all(&self) -> UsecaseResult<Vec<Sale>> {
+            unused()
+        }
+
+        async fn purchase(&self, _: PurchaseInput, _: &str) -> UsecaseResult<Sale> {
+            unused()
+        }
+
+        async fn return_item(
+            &self,
+            _: &str,
+            _: ReturnItemInput,
+            _: &str,
+        ) -> UsecaseResult<Option<ReturnedSale>> {
+            unused()
+        }
+
+        async fn cancel(&self, _: &str, _: &str) -> UsecaseResult<u32> {
+            unused()
+        }
+    }
+
+    #[async_trait]
+    impl ReportInteractor for UnusedInteractor {
+        async fn daily_sales(&self, _: DailySalesInput) -> UsecaseResult<DailySalesSummary> {
+            unused()
+        }
+    }
+
+    #[async_trait]
+    impl InventoryInteractor for UnusedInteractor {
+        async fn find_by_vegetable_id(&self, _: &str) -> UsecaseResult<Option<Inventory>> {
+            unused()
+        }
+
+        async fn find_all(&self) -> UsecaseResult<Vec<Inventory>> {
+            unused()
+        }
+
+        async fn receive(
+            &self,
+            _: &str,
+            _: InventoryQuantityInput,
+        ) -> UsecaseResult<Option<Inventory>> {
+            unused()
+        }
+
+        async fn dispose(
+            &self,
+            _: &str,
+            _: InventoryQuantityInput,
+        ) -> UsecaseResult<Option<Inventory>> {
+            unused()
+        }
+
+        async fn delete(&self, _: &str) -> UsecaseResult<u32> {
+            unused()
+        }
+    }
+
+    #[async_trait]
+    impl CategoryInteractor for UnusedInteractor {
+        async fn find_by_id(&self, _: &str) -> UsecaseResult<Option<Category>> {
+            unused()
+        }
+
+        async fn find_all(&self) -> UsecaseResult<Vec<Category>> {
+            unused()
+        }
+
+        async fn register(&self, _: UpsertCategoryInput) -> UsecaseResult<Category> {
+            unused()
+        }
+
+        async fn update(&self, _: &str, _: UpsertCategoryInput) -> UsecaseResult<Option<Category>> {
+            unused()
+        }
+
+        async fn delete(&self, _: &str) -> UsecaseResult<u32> {
+            unused()
+        }
+    }
+
+    #[async_trait]
+    impl AuditLogInteractor for UnusedInteractor {
+        async fn find_all(&self, _: AuditLogListInput) -> UsecaseResult<Vec<AuditLog>> {
+            unused()
+        }
+    }
+
+    /// インメモリの野菜インタラクターを使用するユースケースインタラクターコンテナ
+    type MemoryInteractorContainer = UsecaseInteractorContainer<
+        MemoryVegetableInteractor,
+        UnusedInteractor,
+        UnusedInteractor,
+        UnusedInteractor,
+        UnusedInteractor,
+        UnusedInteractor,
+    >;
+
+    fn app(
+        repository: MemoryVegetableRepository,
+    ) -> App<
+        impl ServiceFactory<
+            ServiceRequest,
+            Config = (),
+            Response = ServiceResponse<impl MessageBody>,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
+    > {
+        let container: MemoryInteractorContainer = UsecaseInteractorContainer {
+            vegetable: MemoryVegetableInteractor::new(repository, UtcOffset::UTC),
+            sale: UnusedInteractor,
+            report: UnusedInteractor,
+            inventory: UnusedInteractor,
+            category: UnusedInteractor,
+            audit: UnusedInteractor,
+        };
+
+        App::new()
+            .app_data(web::Data::new(container))
+            .app_data(web::JsonConfig::default().error_handler(payload_error_handler))
+            .app_data(web::QueryConfig::default().error_handler(payload_error_handler))
+            .service(vegetable_router::<MemoryInteractorContainer>())
+    }
+
+    fn tomato() -> serde_json::Value {
+        serde_json::json!({ "name": "ﾄﾏﾄ", "unitPrice": 98 })
+    }
+
+    fn request_with_if_match(value: &str) -> HttpRequest {
+        TestRequest::default()
+            .insert_header((IF_MATCH, value))
+            .to_http_request()
+    }
+
+    #[test]
+    fn if_match_version_parses_strong_entity_tag() {
+        assert_eq!(
+            if_match_version(&request_with_if_match("\"3\"")).unwrap(),
+            Some(3)
+        );
+        assert_eq!(if_match_version(&request_with_if_match("*")).unwrap(), None);
+    }
+
+    #[test]
+    fn if_match_version_requires_if_match_header() {
+        let req = TestRequest::default().to_http_request();
+
+        assert!(matches!(
+            if_match_version(&req),
+            Err(ControllerError::PreconditionRequired)
+        ));
+    }
+
+    #[test]
+    fn if_match_version_rejects_other_than_single_strong_version() {
+        for value in ["W/\"3\"", "\"3\", \"4\"", "\"abc\"", "\"-1\"", "3", ""] {
+            assert!(
+                matches!(
+                    if_match_version(&request_with_if_match(value)),
+                    Err(ControllerError::BadRequest(_))
+                ),
+                "{value}"
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn register_and_find_by_id_return_vegetable_with_etag() {
+        let app = test::init_service(app(MemoryVegetableRepository::new())).await;
+
+        let req = TestRequest::post()
+            .uri("/api/vegetables")
+            .set_json(tomato())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(ETAG).unwrap(), "\"1\"");
+        let registered: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(registered["name"], "トマト");
+
+        let id = registered["id"].as_str().unwrap();
+        let req = TestRequest::get()
+            .uri(&format!("/api/vegetables/{id}"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(ETAG).unwrap(), "\"1\"");
+        let found: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(found, registered);
+    }
+
+    #[actix_web::test]
+    async fn find_by_id_returns_not_found_for_unknown_vegetable() {
+        let app = test::init_service(app(MemoryVegetableRepository::new())).await;
+
+        let req = TestRequest::get()
+            .uri(&format!("/api/vegetables/{}", uuid::Uuid::new_v4()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn register_rejects_normalized_duplicate_name() {
+        let app = test::init_service(app(MemoryVegetableRepository::new())).await;
+        let req = TestRequest::post()
+            .uri("/api/vegetables")
+            .set_json(tomato())
+            .to_request();
+        test::call_service(&app, req).await;
+
+        let req = TestRequest::post()
+            .uri("/api/vegetables")
+            .set_json(serde_json::json!({ "name": " トマト ", "unitPrice": 120 }))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn update_checks_if_match_version() {
+        let app = test::init_service(app(MemoryVegetableRepository::new())).await;
+        let req = TestRequest::post()
+            .uri("/api/vegetables")
+            .set_json(tomato())
+            .to_request();
+        let registered: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        let uri = format!("/api/vegetables/{}", registered["id"].as_str().unwrap());
+        let update = serde_json::json!({ "name": "トマト", "unitPrice": 120 });
+
+        let req = TestRequest::put().uri(&uri).set_json(&update).to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::PRECONDITION_REQUIRED);
+
+        let req = TestRequest::put()
+            .uri(&uri)
+            .insert_header((IF_MATCH, "\"2\""))
+            .set_json(&update)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+
+        let req = TestRequest::put()
+            .uri(&uri)
+            .insert_header((IF_MATCH, "\"1\""))
+            .set_json(&update)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(ETAG).unwrap(), "\"2\"");
+    }
+
+    #[actix_web::test]
+    async fn register_price_keeps_version_when_unit_price_is_unchanged() {
+        let app = test::init_service(app(MemoryVegetableRepository::new())).await;
+        let req = TestRequest::post()
+            .uri("/api/vegetables")
+            .set_json(tomato())
+            .to_request();
+        let registered: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        let uri = format!("/api/vegetables/{}", registered["id"].as_str().unwrap());
+
+        for (unit_price, expected) in [(98, "\"1\""), (120, "\"2\"")] {
+            let req = TestRequest::post()
+                .uri(&format!("{uri}/prices"))
+                .set_json(serde_json::json!({ "unitPrice": unit_price }))
+                .to_request();
+            let res = test::call_service(&app, req).await;
+            assert_eq!(res.status(), StatusCode::OK);
+
+            let req = TestRequest::get().uri(&uri).to_request();
+            let res = test::call_service(&app, req).await;
+            assert_eq!(res.headers().get(ETAG).unwrap(), expected, "{unit_price}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn purge_rejects_vegetable_referenced_by_sale() {
+        let repository = MemoryVegetableRepository::new();
+        let app = test::init_service(app(repository.clone())).await;
+        let req = TestRequest::post()
+            .uri("/api/vegetables")
+            .set_json(tomato())
+            .to_request();
+        let registered: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        let id: uuid::Uuid = registered["id"].as_str().unwrap().parse().unwrap();
+        assert!(repository.reference_by_sale(id.into()).unwrap());
+        let uri = format!("/api/vegetables/{id}");
+
+        let req = TestRequest::delete()
+            .uri(&format!("{uri}?permanent=true"))
+            .insert_header((IF_MATCH, "*"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // 物理削除できない野菜も論理削除はできる
+        let req = TestRequest::delete()
+            .uri(&uri)
+            .insert_header((IF_MATCH, "\"1\""))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
@@ -10,6 +10,14 @@ pub enum DomainError {
     #[error("バリデーションエラー: {0}")]
     Validation(Cow<'static, str>),
 
+    /// ドメインルールエラー
+    #[error("ドメインルールエラー: {0}")]
+    DomainRule(Cow<'static, str>),
+
+    /// 競合エラー（更新するエンティティの版が、他の更新によって変わっていた場合）
+    #[error("競合エラー: {0}")]
+    Conflict(Cow<'static, str>),
+
     /// 予期しないエラー
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
@@ -0,0 +1,267 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::primitives::Actor;
+use crate::DomainError;
+
+/// 監査ログを記録するエンティティの種類
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AuditEntityType {
+    /// 野菜
+    Vegetable,
+    /// 販売
+    Sale,
+}
+
+impl AuditEntityType {
+    /// エンティティの種類を表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// エンティティの種類を表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Vegetable => "vegetable",
+            Self::Sale => "sale",
+        }
+    }
+}
+
+impl TryFrom<&str> for AuditEntityType {
+    type Error = DomainError;
+
+    /// コードからエンティティの種類を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - エンティティの種類を表すコード（`vegetable`または`sale`）
+    ///
+    /// # 戻り値
+    ///
+    /// エンティティの種類
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "vegetable" => Ok(Self::Vegetable),
+            "sale" => Ok(Self::Sale),
+            _ => Err(DomainError::Validation(
+                "エンティティの種類はvegetableまたはsaleで指定してください。".into(),
+            )),
+        }
+    }
+}
+
+/// 監査ログに記録する操作
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AuditOperation {
+    /// 登録
+    Register,
+    /// 更新
+    Update,
+    /// 部分更新
+    PartialUpdate,
+    /// 野菜の価格の登録
+    RegisterPrice,
+    /// 削除（野菜の場合は論理削除）
+    Delete,
+    /// 論理削除した野菜の復元
+    Restore,
+    /// 野菜の物理削除
+    Purge,
+    /// 販売明細の置き換え
+    ReplaceDetails,
+}
+
+impl AuditOperation {
+    /// 操作を表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 操作を表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Register => "register",
+            Self::Update => "update",
+            Self::PartialUpdate => "partial_update",
+            Self::RegisterPrice => "register_price",
+            Self::Delete => "delete",
+            Self::Restore => "restore",
+            Self::Purge => "purge",
+            Self::ReplaceDetails => "replace_details",
+        }
+    }
+}
+
+impl TryFrom<&str> for AuditOperation {
+    type Error = DomainError;
+
+    /// コードから操作を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 操作を表すコード
+    ///
+    /// # 戻り値
+    ///
+    /// 操作
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "register" => Ok(Self::Register),
+            "update" => Ok(Self::Update),
+            "partial_update" => Ok(Self::PartialUpdate),
+            "register_price" => Ok(Self::RegisterPrice),
+            "delete" => Ok(Self::Delete),
+            "restore" => Ok(Self::Restore),
+            "purge" => Ok(Self::Purge),
+            "replace_details" => Ok(Self::ReplaceDetails),
+            _ => Err(DomainError::Validation("操作が正しくありません。".into())),
+        }
+    }
+}
+
+/// 監査ログ
+///
+/// 野菜や販売を、いつ、誰が、どのように変更したかを表現する。監査ログは追記のみとし、
+/// 更新または削除しない。
+///
+/// 変更前と変更後の状態は、APIが返す形式のJSON文字列で記録する。登録した場合の変更前と、
+/// 削除した場合の変更後は`None`とする。野菜の価格を登録した場合は、変更後に登録した
+/// 野菜の価格を記録する。
+pub struct AuditLog {
+    /// 監査ログID
+    id: u64,
+    /// 変更したエンティティの種類
+    entity_type: AuditEntityType,
+    /// 変更したエンティティのID
+    entity_id: Uuid,
+    /// 操作
+    operation: AuditOperation,
+    /// 変更前の状態
+    before: Option<String>,
+    /// 変更後の状態
+    after: Option<String>,
+    /// 変更した利用者
+    actor: Actor,
+    /// 記録した日時
+    recorded_at: OffsetDateTime,
+}
+
+impl AuditLog {
+    /// 監査ログを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 監査ログID
+    /// * `entity_type` - 変更したエンティティの種類
+    /// * `entity_id` - 変更したエンティティのID
+    /// * `operation` - 操作
+    /// * `before` - 変更前の状態
+    /// * `after` - 変更後の状態
+    /// * `actor` - 変更した利用者
+    /// * `recorded_at` - 記録した日時
+    ///
+    /// # 戻り値
+    ///
+    /// 監査ログ
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: u64,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+        operation: AuditOperation,
+        before: Option<String>,
+        after: Option<String>,
+        actor: Actor,
+        recorded_at: OffsetDateTime,
+    ) -> Self {
+        Self {
+            id,
+            entity_type,
+            entity_id,
+            operation,
+            before,
+            after,
+            actor,
+            recorded_at,
+        }
+    }
+
+    /// 監査ログIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 監査ログID
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 変更したエンティティの種類を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 変更したエンティティの種類
+    pub fn entity_type(&self) -> AuditEntityType {
+        self.entity_type
+    }
+
+    /// 変更したエンティティのIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 変更したエンティティのID
+    pub fn entity_id(&self) -> Uuid {
+        self.entity_id
+    }
+
+    /// 操作を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 操作
+    pub fn operation(&self) -> AuditOperation {
+        self.operation
+    }
+
+    /// 変更前の状態を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 変更前の状態を表すJSON文字列
+    pub fn before(&self) -> Option<&str> {
+        self.before.as_deref()
+    }
+
+    /// 変更後の状態を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 変更後の状態を表すJSON文字列
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+
+    /// 変更した利用者を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 変更した利用者
+    pub fn actor(&self) -> &Actor {
+        &self.actor
+    }
+
+    /// 記録した日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 記録した日時
+    pub fn recorded_at(&self) -> OffsetDateTime {
+        self.recorded_at
+    }
+}
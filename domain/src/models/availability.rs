@@ -0,0 +1,91 @@
+use time::Month;
+
+use crate::DomainError;
+
+/// 販売する月
+///
+/// 旬の野菜を販売する期間を、1月から12月までの月の集合で表現する。販売する月は、1つ以上の
+/// 月を持つ。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AvailableMonths(u16);
+
+impl AvailableMonths {
+    /// 通年で販売する月
+    pub const ALL: Self = Self(0x0FFF);
+
+    /// 販売する月に、指定した月が含まれるか確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `month` - 月
+    ///
+    /// # 戻り値
+    ///
+    /// 指定した月が含まれる場合は`true`
+    pub fn contains(&self, month: Month) -> bool {
+        self.0 & Self::bit(u8::from(month)) != 0
+    }
+
+    /// 販売する月を、1月から順に返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 1から12までの整数で表した月のベクタ
+    pub fn months(&self) -> Vec<u8> {
+        (1..=12).filter(|&m| self.0 & Self::bit(m) != 0).collect()
+    }
+
+    /// 月を表すビットを返す。
+    fn bit(month: u8) -> u16 {
+        1 << (month - 1)
+    }
+}
+
+impl Default for AvailableMonths {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl<T> TryFrom<&[T]> for AvailableMonths
+where
+    T: Copy + TryInto<u8>,
+{
+    type Error = DomainError;
+
+    /// 1から12までの整数で表した月から、販売する月を構築する。
+    ///
+    /// 重複した月は1つの月とみなす。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 月のスライス
+    ///
+    /// # 戻り値
+    ///
+    /// 販売する月
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(DomainError::Validation(
+                "販売する月を1つ以上指定してください。".into(),
+            ));
+        }
+        let mut bits = 0;
+        for month in value {
+            match (*month).try_into() {
+                Ok(month) if (1..=12).contains(&month) => bits |= Self::bit(month),
+                _ => {
+                    return Err(DomainError::Validation(
+                        "販売する月は1以上12以下で指定してください。".into(),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self(bits))
+    }
+}
@@ -0,0 +1,89 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::primitives::CategoryName;
+use macros::EntityId;
+
+/// カテゴリID
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, EntityId)]
+pub struct CategoryId {
+    value: Uuid,
+}
+
+/// カテゴリ
+///
+/// 葉物野菜や根菜など、野菜を分類するカテゴリを表現する。
+pub struct Category {
+    /// カテゴリID
+    id: CategoryId,
+    /// カテゴリ名
+    name: CategoryName,
+    /// 作成日時
+    created_at: OffsetDateTime,
+    /// 更新日時
+    updated_at: OffsetDateTime,
+}
+
+impl Category {
+    /// カテゴリを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - カテゴリID
+    /// * `name` - カテゴリ名
+    /// * `created_at` - 作成日時
+    /// * `updated_at` - 更新日時
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ
+    pub fn new(
+        id: CategoryId,
+        name: CategoryName,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// カテゴリIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリID
+    pub fn id(&self) -> CategoryId {
+        self.id
+    }
+
+    /// カテゴリ名を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ名
+    pub fn name(&self) -> &CategoryName {
+        &self.name
+    }
+
+    /// 作成日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 作成日時
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at
+    }
+
+    /// 更新日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 更新日時
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at
+    }
+}
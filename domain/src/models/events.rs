@@ -0,0 +1,180 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::primitives::{Amount, Price, Quantity};
+use super::sales::SaleId;
+use super::vegetable::VegetableId;
+
+/// ドメインイベント
+///
+/// 集約は、状態が変化したときにドメインイベントを発行して保持する。リポジトリは、集約を
+/// 永続化するときに、集約が保持するドメインイベントを同一のトランザクションで記録する。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DomainEvent {
+    /// 野菜の価格が変更された
+    VegetablePriceChanged {
+        /// 野菜ID
+        vegetable_id: VegetableId,
+        /// 変更前の単価
+        previous_unit_price: Price,
+        /// 変更後の単価
+        unit_price: Price,
+        /// 変更後の単価の適用開始日時
+        effective_from: OffsetDateTime,
+    },
+
+    /// 野菜の販売が完了した
+    SaleCompleted {
+        /// 販売ID
+        sale_id: SaleId,
+        /// 販売日時
+        sold_at: OffsetDateTime,
+        /// 合計販売金額
+        total_price: Amount,
+    },
+
+    /// 購入された野菜が返品された
+    ItemReturned {
+        /// 販売ID
+        sale_id: SaleId,
+        /// 返品された野菜の野菜ID
+        vegetable_id: VegetableId,
+        /// 返品された数量
+        quantity: Quantity,
+    },
+
+    /// 購入がキャンセルされた
+    SaleCancelled {
+        /// 販売ID
+        sale_id: SaleId,
+    },
+}
+
+impl DomainEvent {
+    /// ドメインイベントの種類を表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントの種類を表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::VegetablePriceChanged { .. } => "vegetable_price_changed",
+            Self::SaleCompleted { .. } => "sale_completed",
+            Self::ItemReturned { .. } => "item_returned",
+            Self::SaleCancelled { .. } => "sale_cancelled",
+        }
+    }
+
+    /// ドメインイベントを発行した集約のIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 集約のID
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            Self::VegetablePriceChanged { vegetable_id, .. } => vegetable_id.value(),
+            Self::SaleCompleted { sale_id, .. }
+            | Self::ItemReturned { sale_id, .. }
+            | Self::SaleCancelled { sale_id } => sale_id.value(),
+        }
+    }
+}
+
+/// アウトボックスから取得した、配信していないドメインイベント
+pub enum PendingEvent {
+    /// ドメインイベントに変換できたドメインイベント
+    Decoded(OutboxEvent),
+
+    /// 記録した時と形式が異なるなどの理由で、ドメインイベントに変換できなかったドメインイベント
+    Undecodable {
+        /// アウトボックスに記録されたドメインイベントのID
+        id: u64,
+        /// ドメインイベントに変換できなかった理由
+        reason: String,
+    },
+}
+
+impl PendingEvent {
+    /// アウトボックスに記録されたドメインイベントのIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// アウトボックスに記録されたドメインイベントのID
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Decoded(event) => event.id(),
+            Self::Undecodable { id, .. } => *id,
+        }
+    }
+}
+
+/// アウトボックスに記録されたドメインイベント
+pub struct OutboxEvent {
+    /// アウトボックスに記録されたドメインイベントのID
+    id: u64,
+    /// ドメインイベント
+    event: DomainEvent,
+    /// ドメインイベントを記録した日時
+    occurred_at: OffsetDateTime,
+    /// ドメインイベントの配信を試みた回数
+    attempts: u32,
+}
+
+impl OutboxEvent {
+    /// アウトボックスに記録されたドメインイベントを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - アウトボックスに記録されたドメインイベントのID
+    /// * `event` - ドメインイベント
+    /// * `occurred_at` - ドメインイベントを記録した日時
+    /// * `attempts` - ドメインイベントの配信を試みた回数
+    ///
+    /// # 戻り値
+    ///
+    /// アウトボックスに記録されたドメインイベント
+    pub fn new(id: u64, event: DomainEvent, occurred_at: OffsetDateTime, attempts: u32) -> Self {
+        Self {
+            id,
+            event,
+            occurred_at,
+            attempts,
+        }
+    }
+
+    /// アウトボックスに記録されたドメインイベントのIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// アウトボックスに記録されたドメインイベントのID
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// ドメインイベントを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベント
+    pub fn event(&self) -> &DomainEvent {
+        &self.event
+    }
+
+    /// ドメインイベントを記録した日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントを記録した日時
+    pub fn occurred_at(&self) -> OffsetDateTime {
+        self.occurred_at
+    }
+
+    /// ドメインイベントの配信を試みた回数を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントの配信を試みた回数
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
@@ -0,0 +1,197 @@
+use time::OffsetDateTime;
+
+use super::primitives::Quantity;
+use super::vegetable::VegetableId;
+use crate::{DomainError, DomainResult};
+
+/// 在庫
+///
+/// 在庫を管理する野菜の在庫数を表現する。在庫を管理していない野菜は、在庫が無限にある
+/// ものとして扱う。
+#[derive(Clone, Debug)]
+pub struct Inventory {
+    /// 野菜ID
+    vegetable_id: VegetableId,
+    /// 在庫数
+    quantity: u32,
+    /// 作成日時
+    created_at: OffsetDateTime,
+    /// 更新日時
+    updated_at: OffsetDateTime,
+}
+
+impl Inventory {
+    /// 在庫数の最大値
+    pub const MAX_QUANTITY: u32 = Quantity::MAX;
+
+    /// 永続化された在庫を再構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    /// * `quantity` - 在庫数
+    /// * `created_at` - 作成日時
+    /// * `updated_at` - 更新日時
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫
+    pub fn reconstruct(
+        vegetable_id: VegetableId,
+        quantity: u32,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Self {
+        Self {
+            vegetable_id,
+            quantity,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// 野菜IDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜ID
+    pub fn vegetable_id(&self) -> VegetableId {
+        self.vegetable_id
+    }
+
+    /// 在庫数を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫数
+    pub fn quantity(&self) -> u32 {
+        self.quantity
+    }
+
+    /// 作成日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 作成日時
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at
+    }
+
+    /// 更新日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 更新日時
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at
+    }
+
+    /// 野菜を入荷する。
+    ///
+    /// # 引数
+    ///
+    /// * `quantity` - 入荷した数量
+    ///
+    /// # 戻り値
+    ///
+    /// 入荷した数量を加えた在庫
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 在庫数が最大値を超える場合
+    pub fn receive(self, quantity: Quantity) -> DomainResult<Self> {
+        self.increase(quantity)
+    }
+
+    /// 傷んだ野菜などを廃棄する。
+    ///
+    /// # 引数
+    ///
+    /// * `quantity` - 廃棄する数量
+    ///
+    /// # 戻り値
+    ///
+    /// 廃棄した数量を減じた在庫
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::DomainRule` - 廃棄する数量が在庫数を超える場合
+    pub fn dispose(self, quantity: Quantity) -> DomainResult<Self> {
+        self.decrease(quantity, || {
+            DomainError::DomainRule("在庫数を超えて廃棄できません。".into())
+        })
+    }
+
+    /// 販売した野菜を在庫から引き当てる。
+    ///
+    /// # 引数
+    ///
+    /// * `quantity` - 販売した数量
+    ///
+    /// # 戻り値
+    ///
+    /// 販売した数量を減じた在庫
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::DomainRule` - 販売した数量が在庫数を超える場合
+    pub fn allocate(self, quantity: Quantity) -> DomainResult<Self> {
+        let vegetable_id = self.vegetable_id;
+        self.decrease(quantity, || {
+            DomainError::DomainRule(
+                format!("在庫が不足しています。(野菜ID: {})", vegetable_id.value()).into(),
+            )
+        })
+    }
+
+    /// 返品された野菜を在庫に戻す。
+    ///
+    /// # 引数
+    ///
+    /// * `quantity` - 返品された数量
+    ///
+    /// # 戻り値
+    ///
+    /// 返品された数量を加えた在庫
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 在庫数が最大値を超える場合
+    pub fn restore(self, quantity: Quantity) -> DomainResult<Self> {
+        self.increase(quantity)
+    }
+
+    /// 在庫数を増やす。
+    fn increase(self, quantity: Quantity) -> DomainResult<Self> {
+        let quantity = self
+            .quantity
+            .checked_add(quantity.value())
+            .filter(|q| *q <= Self::MAX_QUANTITY)
+            .ok_or_else(|| {
+                DomainError::Validation(format!("在庫数は{}以下です。", Self::MAX_QUANTITY).into())
+            })?;
+
+        Ok(Self {
+            quantity,
+            updated_at: OffsetDateTime::now_utc(),
+            ..self
+        })
+    }
+
+    /// 在庫数を減らす。
+    fn decrease<F>(self, quantity: Quantity, insufficient: F) -> DomainResult<Self>
+    where
+        F: FnOnce() -> DomainError,
+    {
+        let quantity = self
+            .quantity
+            .checked_sub(quantity.value())
+            .ok_or_else(insufficient)?;
+
+        Ok(Self {
+            quantity,
+            updated_at: OffsetDateTime::now_utc(),
+            ..self
+        })
+    }
+}
@@ -1,3 +1,10 @@
+pub mod audit;
+pub mod availability;
+pub mod category;
+pub mod events;
+pub mod inventory;
 pub mod primitives;
 pub mod sales;
+pub mod tax;
+pub mod unit;
 pub mod vegetable;
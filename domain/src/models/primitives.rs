@@ -1,12 +1,19 @@
-use crate::DomainError;
+use std::ops::{Mul, Sub};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{DomainError, DomainResult};
 
 /// 価格
 ///
-/// 0以上の整数を持つ価格を表現する。
+/// 0以上、PostgreSQLの`INTEGER`型で表現できる最大値以下の整数を持つ価格を表現する。
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Price(u32);
 
 impl Price {
+    /// 価格の最大値
+    pub const MAX: u32 = i32::MAX as u32;
+
     /// 価格を返す。
     ///
     /// # 戻り値
@@ -17,7 +24,7 @@ impl Price {
     }
 }
 
-macro_rules! price_from_signed_integers {
+macro_rules! price_from_integers {
     ($target:ty) => {
         impl TryFrom<$target> for Price {
             type Error = DomainError;
@@ -34,13 +41,14 @@ macro_rules! price_from_signed_integers {
             ///
             /// # エラー
             ///
-            /// `DomainError::Rule`
+            /// `DomainError::Validation`
             fn try_from(value: $target) -> Result<Self, Self::Error> {
-                if value < 0 as $target {
-                    return Err(DomainError::Validation("数量は0以上です。".into()));
+                match u32::try_from(value) {
+                    Ok(value) if value <= Self::MAX => Ok(Self(value)),
+                    _ => Err(DomainError::Validation(
+                        format!("価格は0以上{}以下です。", Self::MAX).into(),
+                    )),
                 }
-
-                Ok(Self { 0: value as u32 })
             }
         }
     };
@@ -58,31 +66,60 @@ macro_rules! price_from_unsigned_integers {
             /// # 戻り値
             ///
             /// 価格
-            ///
-            /// # エラー
-            ///
-            /// `DomainError::Rule`
             fn from(value: $target) -> Self {
-                Self { 0: value as u32 }
+                Self(value as u32)
             }
         }
     };
 }
 
-price_from_signed_integers!(i8);
-price_from_signed_integers!(i16);
-price_from_signed_integers!(i32);
+price_from_integers!(i8);
+price_from_integers!(i16);
+price_from_integers!(i32);
+price_from_integers!(i64);
+price_from_integers!(u32);
+price_from_integers!(u64);
 price_from_unsigned_integers!(u8);
 price_from_unsigned_integers!(u16);
-price_from_unsigned_integers!(u32);
+
+impl From<Price> for i32 {
+    /// 価格を`INTEGER`型に格納する整数に変換する。
+    ///
+    /// 価格は`INTEGER`型で表現できる最大値以下であるため、変換は失敗しない。
+    fn from(value: Price) -> Self {
+        value.0 as i32
+    }
+}
+
+impl From<Price> for i64 {
+    /// 価格を整数に変換する。
+    fn from(value: Price) -> Self {
+        value.0 as i64
+    }
+}
+
+impl Mul<Quantity> for Price {
+    type Output = Amount;
+
+    /// 価格に数量を乗じた金額を計算する。
+    ///
+    /// 価格と数量はどちらも`INTEGER`型で表現できる最大値以下であるため、金額は
+    /// オーバーフローしない。
+    fn mul(self, rhs: Quantity) -> Self::Output {
+        Amount(self.0 as u64 * rhs.0 as u64)
+    }
+}
 
 /// 数量
 ///
-/// 1以上の整数を持つ数量を表現する。
+/// 1以上、PostgreSQLの`INTEGER`型で表現できる最大値以下の整数を持つ数量を表現する。
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Quantity(u32);
 
 impl Quantity {
+    /// 数量の最大値
+    pub const MAX: u32 = i32::MAX as u32;
+
     /// 数量を返す。
     ///
     /// # 戻り値
@@ -110,13 +147,14 @@ macro_rules! quantity_from_integers {
             ///
             /// # エラー
             ///
-            /// `DomainError::Rule`
+            /// `DomainError::Validation`
             fn try_from(value: $target) -> Result<Self, Self::Error> {
-                if value < 1 as $target {
-                    return Err(DomainError::Validation("数量は1以上です。".into()));
+                match u32::try_from(value) {
+                    Ok(value) if (1..=Self::MAX).contains(&value) => Ok(Self(value)),
+                    _ => Err(DomainError::Validation(
+                        format!("数量は1以上{}以下です。", Self::MAX).into(),
+                    )),
                 }
-
-                Ok(Self { 0: value as u32 })
             }
         }
     };
@@ -125,6 +163,641 @@ macro_rules! quantity_from_integers {
 quantity_from_integers!(i8);
 quantity_from_integers!(i16);
 quantity_from_integers!(i32);
+quantity_from_integers!(i64);
 quantity_from_integers!(u8);
 quantity_from_integers!(u16);
 quantity_from_integers!(u32);
+quantity_from_integers!(u64);
+
+impl From<Quantity> for i32 {
+    /// 数量を`INTEGER`型に格納する整数に変換する。
+    ///
+    /// 数量は`INTEGER`型で表現できる最大値以下であるため、変換は失敗しない。
+    fn from(value: Quantity) -> Self {
+        value.0 as i32
+    }
+}
+
+impl From<Quantity> for i64 {
+    /// 数量を整数に変換する。
+    fn from(value: Quantity) -> Self {
+        value.0 as i64
+    }
+}
+
+impl Sub for Quantity {
+    type Output = Option<Quantity>;
+
+    /// 数量から数量を減じる。
+    ///
+    /// # 戻り値
+    ///
+    /// 減じた数量（減じた結果が1未満になる場合は`None`）
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0
+            .checked_sub(rhs.0)
+            .filter(|value| 1 <= *value)
+            .map(Self)
+    }
+}
+
+/// 重さ
+///
+/// 量り売りする野菜の重さを、1以上、PostgreSQLの`INTEGER`型で表現できる最大値以下の
+/// グラム単位の整数で表現する。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Weight(u32);
+
+impl Weight {
+    /// 重さの最大値（グラム）
+    pub const MAX: u32 = i32::MAX as u32;
+
+    /// 重さをグラム単位で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 重さ（グラム）
+    pub fn grams(&self) -> u32 {
+        self.0
+    }
+}
+
+macro_rules! weight_from_integers {
+    ($target:ty) => {
+        impl TryFrom<$target> for Weight {
+            type Error = DomainError;
+
+            /// グラム単位の整数から重さを構築する。
+            ///
+            /// # 引数
+            ///
+            /// * `value` - 重さ（グラム）
+            ///
+            /// # 戻り値
+            ///
+            /// 重さ
+            ///
+            /// # エラー
+            ///
+            /// `DomainError::Validation`
+            fn try_from(value: $target) -> Result<Self, Self::Error> {
+                match u32::try_from(value) {
+                    Ok(value) if (1..=Self::MAX).contains(&value) => Ok(Self(value)),
+                    _ => Err(DomainError::Validation(
+                        format!("重さは1g以上{}g以下です。", Self::MAX).into(),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+weight_from_integers!(i8);
+weight_from_integers!(i16);
+weight_from_integers!(i32);
+weight_from_integers!(i64);
+weight_from_integers!(u8);
+weight_from_integers!(u16);
+weight_from_integers!(u32);
+weight_from_integers!(u64);
+
+impl From<Weight> for i32 {
+    /// 重さを`INTEGER`型に格納するグラム単位の整数に変換する。
+    ///
+    /// 重さは`INTEGER`型で表現できる最大値以下であるため、変換は失敗しない。
+    fn from(value: Weight) -> Self {
+        value.0 as i32
+    }
+}
+
+/// 金額
+///
+/// 価格に数量を乗じた小計や、小計を合計した金額など、0以上の整数を持つ金額を表現する。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(u64);
+
+impl Amount {
+    /// 金額0
+    pub const ZERO: Self = Self(0);
+
+    /// 金額を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 金額
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// 金額を加算する。
+    ///
+    /// # 引数
+    ///
+    /// * `rhs` - 加算する金額
+    ///
+    /// # 戻り値
+    ///
+    /// 加算した金額（オーバーフローした場合は`None`）
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// 金額を合計する。
+    ///
+    /// # 引数
+    ///
+    /// * `amounts` - 合計する金額
+    ///
+    /// # 戻り値
+    ///
+    /// 合計した金額
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation` - 合計した金額がオーバーフローした場合
+    pub fn checked_sum<I>(amounts: I) -> DomainResult<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        amounts
+            .into_iter()
+            .try_fold(Self::ZERO, Self::checked_add)
+            .ok_or_else(|| DomainError::Validation("金額が大きすぎます。".into()))
+    }
+}
+
+impl From<Price> for Amount {
+    fn from(value: Price) -> Self {
+        Self(value.0 as u64)
+    }
+}
+
+impl From<u32> for Amount {
+    fn from(value: u32) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u64> for Amount {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+macro_rules! amount_from_signed_integers {
+    ($target:ty) => {
+        impl TryFrom<$target> for Amount {
+            type Error = DomainError;
+
+            /// 整数から金額を構築する。
+            ///
+            /// # 引数
+            ///
+            /// * `value` - 金額
+            ///
+            /// # 戻り値
+            ///
+            /// 金額
+            ///
+            /// # エラー
+            ///
+            /// `DomainError::Validation`
+            fn try_from(value: $target) -> Result<Self, Self::Error> {
+                u64::try_from(value)
+                    .map(Self)
+                    .map_err(|_| DomainError::Validation("金額は0以上です。".into()))
+            }
+        }
+    };
+}
+
+amount_from_signed_integers!(i32);
+amount_from_signed_integers!(i64);
+
+macro_rules! integers_from_amount {
+    ($target:ty) => {
+        impl TryFrom<Amount> for $target {
+            type Error = DomainError;
+
+            /// 金額を整数に変換する。
+            ///
+            /// # 引数
+            ///
+            /// * `value` - 金額
+            ///
+            /// # 戻り値
+            ///
+            /// 整数
+            ///
+            /// # エラー
+            ///
+            /// `DomainError::Validation` - 金額が整数で表現できる範囲を超える場合
+            fn try_from(value: Amount) -> Result<Self, Self::Error> {
+                <$target>::try_from(value.0)
+                    .map_err(|_| DomainError::Validation("金額が大きすぎます。".into()))
+            }
+        }
+    };
+}
+
+integers_from_amount!(i32);
+integers_from_amount!(i64);
+integers_from_amount!(u32);
+
+/// 野菜名
+///
+/// 全角英数字を半角に、半角カナを全角にそろえ（Unicode正規化形式KC）、前後の空白を除いた
+/// 1文字以上80文字以下の文字列を持つ野菜名を表現する。
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VegetableName(String);
+
+impl VegetableName {
+    /// 野菜名の最大文字数
+    pub const MAX_LENGTH: usize = 80;
+
+    /// 野菜名を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜名
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    /// 文字列を野菜名と同じ規則で正規化する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 正規化する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// 正規化した文字列
+    pub fn normalize(value: &str) -> String {
+        normalize_text(value)
+    }
+}
+
+impl TryFrom<&str> for VegetableName {
+    type Error = DomainError;
+
+    /// 文字列から野菜名を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 野菜名を構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜名
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = Self::normalize(value);
+        if value.is_empty() {
+            return Err(DomainError::Validation("野菜名を指定してください。".into()));
+        }
+        if Self::MAX_LENGTH < value.chars().count() {
+            return Err(DomainError::Validation(
+                format!("野菜名は{}文字以下です。", Self::MAX_LENGTH).into(),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<String> for VegetableName {
+    type Error = DomainError;
+
+    /// 文字列から野菜名を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 野菜名を構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜名
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// カテゴリ名
+///
+/// 野菜名と同じ規則で正規化した、1文字以上40文字以下の文字列を持つカテゴリ名を表現する。
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CategoryName(String);
+
+impl CategoryName {
+    /// カテゴリ名の最大文字数
+    pub const MAX_LENGTH: usize = 40;
+
+    /// カテゴリ名を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ名
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for CategoryName {
+    type Error = DomainError;
+
+    /// 文字列からカテゴリ名を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - カテゴリ名を構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ名
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = normalize_text(value);
+        if value.is_empty() {
+            return Err(DomainError::Validation(
+                "カテゴリ名を指定してください。".into(),
+            ));
+        }
+        if Self::MAX_LENGTH < value.chars().count() {
+            return Err(DomainError::Validation(
+                format!("カテゴリ名は{}文字以下です。", Self::MAX_LENGTH).into(),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<String> for CategoryName {
+    type Error = DomainError;
+
+    /// 文字列からカテゴリ名を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - カテゴリ名を構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ名
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// タグ
+///
+/// 野菜名と同じ規則で正規化した、1文字以上30文字以下の文字列を持つ、野菜に自由に付ける
+/// タグを表現する。
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tag(String);
+
+impl Tag {
+    /// タグの最大文字数
+    pub const MAX_LENGTH: usize = 30;
+
+    /// タグを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// タグ
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Tag {
+    type Error = DomainError;
+
+    /// 文字列からタグを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - タグを構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// タグ
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = normalize_text(value);
+        if value.is_empty() {
+            return Err(DomainError::Validation("タグを指定してください。".into()));
+        }
+        if Self::MAX_LENGTH < value.chars().count() {
+            return Err(DomainError::Validation(
+                format!("タグは{}文字以下です。", Self::MAX_LENGTH).into(),
+            ));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<String> for Tag {
+    type Error = DomainError;
+
+    /// 文字列からタグを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - タグを構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// タグ
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// 利用者
+///
+/// 野菜や販売を変更した利用者を表現する。前後の空白を除いた、1文字以上100文字以下の
+/// 文字列を持つ。
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Actor(String);
+
+impl Actor {
+    /// 利用者の最大文字数
+    pub const MAX_LENGTH: usize = 100;
+
+    /// 利用者が指定されていない場合の利用者
+    pub const ANONYMOUS: &'static str = "anonymous";
+
+    /// 利用者を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 利用者
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Actor {
+    type Error = DomainError;
+
+    /// 文字列から利用者を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 利用者を構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// 利用者
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(DomainError::Validation("利用者を指定してください。".into()));
+        }
+        if Self::MAX_LENGTH < value.chars().count() {
+            return Err(DomainError::Validation(
+                format!("利用者は{}文字以下です。", Self::MAX_LENGTH).into(),
+            ));
+        }
+
+        Ok(Self(value.to_string()))
+    }
+}
+
+impl TryFrom<String> for Actor {
+    type Error = DomainError;
+
+    /// 文字列から利用者を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 利用者を構築する文字列
+    ///
+    /// # 戻り値
+    ///
+    /// 利用者
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// 全角英数字を半角に、半角カナを全角にそろえ（Unicode正規化形式KC）、前後の空白を除く。
+///
+/// # 引数
+///
+/// * `value` - 正規化する文字列
+///
+/// # 戻り値
+///
+/// 正規化した文字列
+fn normalize_text(value: &str) -> String {
+    value.nfkc().collect::<String>().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quantity(value: u32) -> Quantity {
+        Quantity::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn quantity_sub_returns_remaining_quantity() {
+        assert_eq!(quantity(3) - quantity(1), Some(quantity(2)));
+    }
+
+    #[test]
+    fn quantity_sub_returns_none_when_less_than_one() {
+        assert_eq!(quantity(3) - quantity(3), None);
+        assert_eq!(quantity(1) - quantity(3), None);
+    }
+
+    #[test]
+    fn amount_checked_sum_sums_amounts() {
+        let amounts = [100u64, 200, 300].map(Amount::from);
+
+        assert_eq!(Amount::checked_sum(amounts).unwrap(), Amount::from(600u64));
+        assert_eq!(Amount::checked_sum([]).unwrap(), Amount::ZERO);
+    }
+
+    #[test]
+    fn amount_checked_sum_rejects_overflow() {
+        let amounts = [u64::MAX, 1].map(Amount::from);
+
+        assert!(matches!(
+            Amount::checked_sum(amounts),
+            Err(DomainError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn vegetable_name_is_normalized_with_nfkc() {
+        let name = |value: &str| VegetableName::try_from(value).unwrap();
+
+        assert_eq!(name("ﾄﾏﾄ").value(), "トマト");
+        assert_eq!(name("ｼﾞｬｶﾞｲﾓ").value(), "ジャガイモ");
+        assert_eq!(name("ＡＢＣ１２３").value(), "ABC123");
+        assert_eq!(name("\u{3000} トマト \t").value(), "トマト");
+        assert_eq!(name("ﾄﾏﾄ"), name("トマト"));
+    }
+
+    #[test]
+    fn vegetable_name_rejects_blank_name() {
+        assert!(matches!(
+            VegetableName::try_from(""),
+            Err(DomainError::Validation(_))
+        ));
+        assert!(matches!(
+            VegetableName::try_from(" \u{3000}"),
+            Err(DomainError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn vegetable_name_length_is_counted_after_normalization() {
+        let max = "ト".repeat(VegetableName::MAX_LENGTH);
+        assert!(VegetableName::try_from(max.as_str()).is_ok());
+        // 半角の「ﾄﾞ」は2文字だが、正規化すると1文字の「ド」になる
+        let max = "ﾄﾞ".repeat(VegetableName::MAX_LENGTH);
+        assert!(VegetableName::try_from(max.as_str()).is_ok());
+
+        let too_long = "ト".repeat(VegetableName::MAX_LENGTH + 1);
+        assert!(matches!(
+            VegetableName::try_from(too_long.as_str()),
+            Err(DomainError::Validation(_))
+        ));
+    }
+}
@@ -1,10 +1,15 @@
+use std::collections::HashSet;
 use std::hash::Hash;
 
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use super::primitives::{Price, Quantity};
-use super::vegetable::Vegetable;
+use super::events::DomainEvent;
+use super::primitives::{Amount, Price, Quantity, Weight};
+use super::tax::{TaxAmount, TaxInclusion, TaxPolicy, TaxRate};
+use super::unit::UnitOfMeasure;
+use super::vegetable::{Vegetable, VegetableId};
+use crate::{DomainError, DomainResult};
 use macros::EntityId;
 
 /// 販売ID
@@ -21,39 +26,132 @@ pub struct Sale {
     sold_at: OffsetDateTime,
     /// 販売明細
     sale_details: Vec<SaleDetail>,
-    /// 合計販売金額
-    total_price: u32,
+    /// 消費税の計算方法
+    tax_policy: TaxPolicy,
+    /// 販売明細の小計の合計
+    subtotal: Amount,
+    /// 税率ごとの消費税
+    taxes: Vec<TaxAmount>,
+    /// 消費税額の合計
+    tax_amount: Amount,
+    /// 合計販売金額（消費税を含む）
+    total_price: Amount,
     /// 作成日時
     created_at: OffsetDateTime,
     /// 更新日時
-    updated_at: Option<OffsetDateTime>,
+    updated_at: OffsetDateTime,
+    /// 発行したドメインイベント
+    events: Vec<DomainEvent>,
 }
 
 impl Sale {
     /// 販売を構築する。
     ///
+    /// 販売が完了したことを示すドメインイベントを発行する。
+    ///
     /// # 引数
     ///
     /// * `sold_at` - 販売日時
     /// * `sale_details` - 販売明細
+    /// * `tax_policy` - 消費税の計算方法
     ///
     /// # 戻り値
     ///
     /// 販売
-    pub fn new(sold_at: OffsetDateTime, sale_details: Vec<SaleDetail>) -> Self {
-        let total_price = sale_details
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::DomainRule` - 販売明細が存在しない場合、または同じ野菜を販売した
+    ///   販売明細が複数存在する場合
+    /// * `DomainError::Validation` - 合計販売金額が扱える範囲を超える場合
+    pub fn new(
+        sold_at: OffsetDateTime,
+        sale_details: Vec<SaleDetail>,
+        tax_policy: TaxPolicy,
+    ) -> DomainResult<Self> {
+        if sale_details.is_empty() {
+            return Err(DomainError::DomainRule(
+                "販売には1つ以上の販売明細が必要です。".into(),
+            ));
+        }
+        let mut vegetable_ids = HashSet::with_capacity(sale_details.len());
+        if !sale_details
             .iter()
-            .map(|sd| sd.sold_unit_price().value() * sd.sold_quantity().value())
-            .sum();
+            .all(|sd| vegetable_ids.insert(sd.vegetable().id()))
+        {
+            return Err(DomainError::DomainRule(
+                "同じ野菜を複数の販売明細に記録できません。".into(),
+            ));
+        }
+        let amounts = SaleAmounts::calculate(&sale_details, tax_policy)?;
 
-        Self {
-            id: SaleId::default(),
+        let now = OffsetDateTime::now_utc();
+        let id = SaleId::generate();
+        let events = vec![DomainEvent::SaleCompleted {
+            sale_id: id,
+            sold_at,
+            total_price: amounts.total_price,
+        }];
+
+        Ok(Self {
+            id,
             sold_at,
             sale_details,
-            total_price,
-            created_at: OffsetDateTime::now_utc(),
-            updated_at: None,
-        }
+            tax_policy,
+            subtotal: amounts.subtotal,
+            taxes: amounts.taxes,
+            tax_amount: amounts.tax_amount,
+            total_price: amounts.total_price,
+            created_at: now,
+            updated_at: now,
+            events,
+        })
+    }
+
+    /// 永続化された販売を再構築する。
+    ///
+    /// 小計、税率ごとの消費税及び合計販売金額は、販売明細と消費税の計算方法から
+    /// 計算し直す。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売ID
+    /// * `sold_at` - 販売日時
+    /// * `sale_details` - 販売明細
+    /// * `tax_policy` - 消費税の計算方法
+    /// * `created_at` - 作成日時
+    /// * `updated_at` - 更新日時
+    ///
+    /// # 戻り値
+    ///
+    /// 販売
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 合計販売金額が扱える範囲を超える場合
+    pub fn reconstruct(
+        id: SaleId,
+        sold_at: OffsetDateTime,
+        sale_details: Vec<SaleDetail>,
+        tax_policy: TaxPolicy,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> DomainResult<Self> {
+        let amounts = SaleAmounts::calculate(&sale_details, tax_policy)?;
+
+        Ok(Self {
+            id,
+            sold_at,
+            sale_details,
+            tax_policy,
+            subtotal: amounts.subtotal,
+            taxes: amounts.taxes,
+            tax_amount: amounts.tax_amount,
+            total_price: amounts.total_price,
+            created_at,
+            updated_at,
+            events: Vec::new(),
+        })
     }
 
     /// 販売IDを返す。
@@ -83,12 +181,50 @@ impl Sale {
         &self.sale_details
     }
 
+    /// 消費税の計算方法を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 消費税の計算方法
+    pub fn tax_policy(&self) -> TaxPolicy {
+        self.tax_policy
+    }
+
+    /// 販売明細の小計の合計を返す。
+    ///
+    /// 価格が税込価格の場合は消費税を含み、税抜価格の場合は消費税を含まない。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売明細の小計の合計
+    pub fn subtotal(&self) -> Amount {
+        self.subtotal
+    }
+
+    /// 税率ごとの消費税を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 税率の順に並べた、税率ごとの消費税
+    pub fn taxes(&self) -> &[TaxAmount] {
+        &self.taxes
+    }
+
+    /// 消費税額の合計を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 消費税額の合計
+    pub fn tax_amount(&self) -> Amount {
+        self.tax_amount
+    }
+
     /// 合計販売金額を返す。
     ///
     /// # 戻り値
     ///
-    /// 合計販売金額。
-    pub fn total_price(&self) -> u32 {
+    /// 消費税を含めた合計販売金額。
+    pub fn total_price(&self) -> Amount {
         self.total_price
     }
 
@@ -101,16 +237,194 @@ impl Sale {
         self.created_at
     }
 
+    /// 購入した野菜のうち、1つの野菜を返品する。
+    ///
+    /// 販売明細は値オブジェクトであるため、既存の販売明細をすべて破棄して、新たな販売明細を
+    /// 作成する。返品した結果、数量が0になった販売明細は作成しない。
+    /// 販売明細がひとつも残らない場合は、購入した野菜がすべて返品されたとして、
+    /// 返品する前の販売明細を持つ販売を`ReturnedSale::FullyReturned`で返す。
+    /// いずれの場合も、野菜が返品されたことを示すドメインイベントを発行する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 返品する野菜の野菜ID
+    /// * `quantity` - 返品する数量
+    ///
+    /// # 戻り値
+    ///
+    /// 返品した結果
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::DomainRule` - 返品する野菜を購入していない場合、または返品する数量が
+    ///   購入した数量を超える場合
+    /// * `DomainError::Validation` - 合計販売金額が扱える範囲を超える場合
+    pub fn return_item(
+        self,
+        vegetable_id: VegetableId,
+        quantity: Quantity,
+    ) -> DomainResult<ReturnedSale> {
+        let target = self
+            .sale_details
+            .iter()
+            .find(|sd| sd.vegetable().id() == vegetable_id)
+            .ok_or_else(|| DomainError::DomainRule("返品する野菜を購入していません。".into()))?;
+        if target.sold_quantity() < quantity {
+            return Err(DomainError::DomainRule(
+                "購入した数量を超えて返品できません。".into(),
+            ));
+        }
+        // 同じ野菜を複数の販売明細に記録できないため、販売明細が1つで、その数量をすべて
+        // 返品する場合は、販売明細がひとつも残らない
+        let fully_returned = self.sale_details.len() == 1 && target.sold_quantity() == quantity;
+        let mut events = self.events;
+        events.push(DomainEvent::ItemReturned {
+            sale_id: self.id,
+            vegetable_id,
+            quantity,
+        });
+        if fully_returned {
+            return Ok(ReturnedSale::FullyReturned(Self { events, ..self }));
+        }
+
+        let mut sale_details = Vec::with_capacity(self.sale_details.len());
+        for sd in self.sale_details {
+            let sold_quantity = if sd.vegetable().id() == vegetable_id {
+                // 返品する数量が購入した数量を超えないことは確認済みであるため、
+                // 数量が1未満になる場合は、購入した数量をすべて返品した場合である
+                match sd.sold_quantity() - quantity {
+                    Some(remaining) => remaining,
+                    None => continue,
+                }
+            } else {
+                sd.sold_quantity()
+            };
+            let sold_unit_price = sd.sold_unit_price();
+            let unit = sd.unit();
+            let sold_weight = sd.sold_weight();
+            let tax_rate = sd.tax_rate();
+            sale_details.push(SaleDetail::new(
+                sd.vegetable,
+                sold_unit_price,
+                unit,
+                sold_quantity,
+                sold_weight,
+                tax_rate,
+            )?);
+        }
+        let amounts = SaleAmounts::calculate(&sale_details, self.tax_policy)?;
+
+        Ok(ReturnedSale::Remaining(Self {
+            sale_details,
+            subtotal: amounts.subtotal,
+            taxes: amounts.taxes,
+            tax_amount: amounts.tax_amount,
+            total_price: amounts.total_price,
+            updated_at: OffsetDateTime::now_utc(),
+            events,
+            ..self
+        }))
+    }
+
+    /// 購入した野菜をすべて返品して、購入をキャンセルする。
+    ///
+    /// 購入がキャンセルされたことを示すドメインイベントを発行する。
+    ///
+    /// # 戻り値
+    ///
+    /// キャンセルした販売
+    pub fn cancel(mut self) -> Self {
+        self.events
+            .push(DomainEvent::SaleCancelled { sale_id: self.id });
+
+        self
+    }
+
+    /// 発行したドメインイベントを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 発行した順に並べたドメインイベントのスライス
+    pub fn events(&self) -> &[DomainEvent] {
+        &self.events
+    }
+
     /// 更新日時を返す。
     ///
     /// # 戻り値
     ///
     /// 更新日時
-    pub fn updated_at(&self) -> Option<OffsetDateTime> {
+    pub fn updated_at(&self) -> OffsetDateTime {
         self.updated_at
     }
 }
 
+/// 返品した結果
+pub enum ReturnedSale {
+    /// 販売明細が残っている販売
+    Remaining(Sale),
+    /// 購入した野菜がすべて返品された販売（返品する前の販売明細を持つ）
+    FullyReturned(Sale),
+}
+
+/// 販売の金額
+struct SaleAmounts {
+    /// 販売明細の小計の合計
+    subtotal: Amount,
+    /// 税率ごとの消費税
+    taxes: Vec<TaxAmount>,
+    /// 消費税額の合計
+    tax_amount: Amount,
+    /// 合計販売金額
+    total_price: Amount,
+}
+
+impl SaleAmounts {
+    /// 販売明細から販売の金額を計算する。
+    ///
+    /// 消費税は、販売明細ごとではなく、販売全体で税率ごとに計算する。
+    /// 販売の金額は永続化層の`INTEGER`型に格納するため、合計販売金額が`INTEGER`型で
+    /// 表現できる範囲を超える場合はエラーとする。
+    ///
+    /// # 引数
+    ///
+    /// * `sale_details` - 販売明細
+    /// * `tax_policy` - 消費税の計算方法
+    ///
+    /// # 戻り値
+    ///
+    /// 販売の金額
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 合計販売金額が扱える範囲を超える場合
+    fn calculate(sale_details: &[SaleDetail], tax_policy: TaxPolicy) -> DomainResult<Self> {
+        let too_large = |_| DomainError::Validation("合計販売金額が大きすぎます。".into());
+        let subtotal =
+            Amount::checked_sum(sale_details.iter().map(|sd| sd.subtotal())).map_err(too_large)?;
+        let taxes = tax_policy
+            .calculate(sale_details.iter().map(|sd| (sd.tax_rate(), sd.subtotal())))
+            .map_err(too_large)?;
+        let tax_amount =
+            Amount::checked_sum(taxes.iter().map(|t| t.tax_amount())).map_err(too_large)?;
+        let total_price = match tax_policy.inclusion {
+            TaxInclusion::Inclusive => subtotal,
+            TaxInclusion::Exclusive => {
+                Amount::checked_sum([subtotal, tax_amount]).map_err(too_large)?
+            }
+        };
+        // 小計の合計と消費税は、合計販売金額以下である
+        i32::try_from(total_price).map_err(too_large)?;
+
+        Ok(Self {
+            subtotal,
+            taxes,
+            tax_amount,
+            total_price,
+        })
+    }
+}
+
 /// 販売明細ID
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, EntityId)]
 pub struct SaleDetailId {
@@ -118,6 +432,8 @@ pub struct SaleDetailId {
 }
 
 /// 販売明細
+///
+/// 量り売りする野菜の販売明細は、同じ重さの野菜を販売した数量を記録する。
 pub struct SaleDetail {
     /// 販売明細ID
     id: SaleDetailId,
@@ -125,8 +441,16 @@ pub struct SaleDetail {
     vegetable: Vegetable,
     /// 野菜を販売した単価
     sold_unit_price: Price,
+    /// 野菜を販売した時の単位
+    unit: UnitOfMeasure,
     /// 野菜を販売した数量
     sold_quantity: Quantity,
+    /// 量り売りする野菜を販売した重さ（野菜1つあたり）
+    sold_weight: Option<Weight>,
+    /// 野菜を販売した時の消費税率
+    tax_rate: TaxRate,
+    /// 野菜1つあたりの価格
+    item_price: Price,
 }
 
 impl SaleDetail {
@@ -136,16 +460,81 @@ impl SaleDetail {
     ///
     /// * `vegetable` - 販売した野菜
     /// * `sold_unit_price` - 野菜を販売した単価
+    /// * `unit` - 野菜を販売した時の単位
+    /// * `sold_quantity` - 野菜を販売した数量
+    /// * `sold_weight` - 量り売りする野菜を販売した重さ（野菜1つあたり）
+    /// * `tax_rate` - 野菜を販売した時の消費税率
+    ///
+    /// # 戻り値
+    ///
+    /// 販売明細
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 量り売りする野菜の重さが指定されていない場合、
+    ///   量り売り以外の野菜に重さが指定されている場合、または野菜1つあたりの価格が扱える
+    ///   範囲を超える場合
+    pub fn new(
+        vegetable: Vegetable,
+        sold_unit_price: Price,
+        unit: UnitOfMeasure,
+        sold_quantity: Quantity,
+        sold_weight: Option<Weight>,
+        tax_rate: TaxRate,
+    ) -> DomainResult<Self> {
+        Self::reconstruct(
+            SaleDetailId::generate(),
+            vegetable,
+            sold_unit_price,
+            unit,
+            sold_quantity,
+            sold_weight,
+            tax_rate,
+        )
+    }
+
+    /// 永続化された販売明細を再構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売明細ID
+    /// * `vegetable` - 販売した野菜
+    /// * `sold_unit_price` - 野菜を販売した単価
+    /// * `unit` - 野菜を販売した時の単位
     /// * `sold_quantity` - 野菜を販売した数量
+    /// * `sold_weight` - 量り売りする野菜を販売した重さ（野菜1つあたり）
+    /// * `tax_rate` - 野菜を販売した時の消費税率
     ///
     /// # 戻り値
-    pub fn new(vegetable: Vegetable, sold_unit_price: Price, sold_quantity: Quantity) -> Self {
-        Self {
-            id: SaleDetailId::default(),
+    ///
+    /// 販売明細
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 量り売りする野菜の重さが指定されていない場合、
+    ///   量り売り以外の野菜に重さが指定されている場合、または野菜1つあたりの価格が扱える
+    ///   範囲を超える場合
+    pub fn reconstruct(
+        id: SaleDetailId,
+        vegetable: Vegetable,
+        sold_unit_price: Price,
+        unit: UnitOfMeasure,
+        sold_quantity: Quantity,
+        sold_weight: Option<Weight>,
+        tax_rate: TaxRate,
+    ) -> DomainResult<Self> {
+        let item_price = unit.price_of(sold_unit_price, sold_weight)?;
+
+        Ok(Self {
+            id,
             vegetable,
             sold_unit_price,
+            unit,
             sold_quantity,
-        }
+            sold_weight,
+            tax_rate,
+            item_price,
+        })
     }
 
     /// 販売明細IDを返す。
@@ -175,6 +564,15 @@ impl SaleDetail {
         self.sold_unit_price
     }
 
+    /// 野菜を販売した時の単位を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜を販売した時の単位
+    pub fn unit(&self) -> UnitOfMeasure {
+        self.unit
+    }
+
     /// 野菜を販売した数量を返す。
     ///
     /// # 戻り値
@@ -183,4 +581,289 @@ impl SaleDetail {
     pub fn sold_quantity(&self) -> Quantity {
         self.sold_quantity
     }
+
+    /// 量り売りする野菜を販売した重さを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜1つあたりの重さ（量り売り以外の場合は`None`）
+    pub fn sold_weight(&self) -> Option<Weight> {
+        self.sold_weight
+    }
+
+    /// 野菜を販売した時の消費税率を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜を販売した時の消費税率
+    pub fn tax_rate(&self) -> TaxRate {
+        self.tax_rate
+    }
+
+    /// 野菜1つあたりの価格を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 量り売りの場合は単価と重さから計算した価格、量り売り以外の場合は単価
+    pub fn item_price(&self) -> Price {
+        self.item_price
+    }
+
+    /// 小計を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜1つあたりの価格と数量を乗じた小計
+    pub fn subtotal(&self) -> Amount {
+        self.item_price * self.sold_quantity
+    }
+
+    /// 在庫から増減する数量を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 量り売りの場合は野菜1つあたりの重さと数量を乗じた重さ（グラム）、量り売り以外の場合は
+    /// 数量
+    pub fn stock_quantity(&self) -> u64 {
+        let quantity = u64::from(self.sold_quantity.value());
+        match self.sold_weight {
+            Some(weight) => quantity * u64::from(weight.grams()),
+            None => quantity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::availability::AvailableMonths;
+    use crate::models::inventory::Inventory;
+    use crate::models::primitives::VegetableName;
+    use crate::models::tax::RoundingMode;
+
+    fn vegetable(name: &str, unit_price: u32, tax_rate: TaxRate) -> Vegetable {
+        vegetable_with_id(VegetableId::generate(), name, unit_price, tax_rate)
+    }
+
+    fn vegetable_with_id(
+        id: VegetableId,
+        name: &str,
+        unit_price: u32,
+        tax_rate: TaxRate,
+    ) -> Vegetable {
+        let now = OffsetDateTime::now_utc();
+        Vegetable::new(
+            id,
+            VegetableName::try_from(name).unwrap(),
+            Price::try_from(unit_price).unwrap(),
+            tax_rate,
+            UnitOfMeasure::Piece,
+            None,
+            vec![],
+            AvailableMonths::ALL,
+            true,
+            1,
+            now,
+            now,
+        )
+    }
+
+    fn detail(vegetable: Vegetable, quantity: u32) -> SaleDetail {
+        let unit_price = vegetable.unit_price();
+        let tax_rate = vegetable.tax_rate();
+        SaleDetail::new(
+            vegetable,
+            unit_price,
+            UnitOfMeasure::Piece,
+            Quantity::try_from(quantity).unwrap(),
+            None,
+            tax_rate,
+        )
+        .unwrap()
+    }
+
+    fn sale(details: Vec<SaleDetail>) -> Sale {
+        let tax_policy = TaxPolicy {
+            inclusion: TaxInclusion::Exclusive,
+            rounding: RoundingMode::Floor,
+        };
+        Sale::new(OffsetDateTime::now_utc(), details, tax_policy).unwrap()
+    }
+
+    fn remaining(returned: ReturnedSale) -> Sale {
+        match returned {
+            ReturnedSale::Remaining(sale) => sale,
+            ReturnedSale::FullyReturned(_) => panic!("販売明細が残っていません。"),
+        }
+    }
+
+    #[test]
+    fn new_calculates_taxes_once_per_rate() {
+        let sale = sale(vec![
+            detail(vegetable("トマト", 98, TaxRate::Reduced), 1),
+            detail(vegetable("キャベツ", 98, TaxRate::Reduced), 1),
+            detail(vegetable("花束", 300, TaxRate::Standard), 1),
+        ]);
+
+        // 販売明細ごとに端数処理すると軽減税率の消費税は7円+7円=14円になるが、
+        // 税率ごとに1回だけ端数処理するため196円の8%を切り捨てた15円になる
+        assert_eq!(sale.subtotal(), Amount::from(496u32));
+        let taxes: Vec<_> = sale
+            .taxes()
+            .iter()
+            .map(|t| (t.rate(), t.tax_amount().value()))
+            .collect();
+        assert_eq!(taxes, vec![(TaxRate::Reduced, 15), (TaxRate::Standard, 30)]);
+        assert_eq!(sale.tax_amount(), Amount::from(45u32));
+        assert_eq!(sale.total_price(), Amount::from(541u32));
+        assert_eq!(
+            sale.events(),
+            &[DomainEvent::SaleCompleted {
+                sale_id: sale.id(),
+                sold_at: sale.sold_at(),
+                total_price: sale.total_price(),
+            }]
+        );
+    }
+
+    #[test]
+    fn new_rejects_sale_without_details() {
+        let result = Sale::new(OffsetDateTime::now_utc(), vec![], TaxPolicy::default());
+
+        assert!(matches!(result, Err(DomainError::DomainRule(_))));
+    }
+
+    #[test]
+    fn new_rejects_same_vegetable_in_multiple_details() {
+        let id = VegetableId::generate();
+        let result = Sale::new(
+            OffsetDateTime::now_utc(),
+            vec![
+                detail(vegetable_with_id(id, "トマト", 100, TaxRate::Reduced), 1),
+                detail(vegetable_with_id(id, "トマト", 100, TaxRate::Reduced), 2),
+            ],
+            TaxPolicy::default(),
+        );
+
+        assert!(matches!(result, Err(DomainError::DomainRule(_))));
+    }
+
+    #[test]
+    fn return_item_reduces_quantity_and_recalculates_amounts() {
+        let tomato = vegetable("トマト", 100, TaxRate::Reduced);
+        let tomato_id = tomato.id();
+        let sale = sale(vec![detail(tomato, 3)]);
+        let sale_id = sale.id();
+
+        let sale = remaining(
+            sale.return_item(tomato_id, Quantity::try_from(1).unwrap())
+                .unwrap(),
+        );
+
+        assert_eq!(sale.sale_details().len(), 1);
+        assert_eq!(sale.sale_details()[0].sold_quantity().value(), 2);
+        assert_eq!(sale.subtotal(), Amount::from(200u32));
+        assert_eq!(sale.total_price(), Amount::from(216u32));
+        assert_eq!(
+            sale.events().last(),
+            Some(&DomainEvent::ItemReturned {
+                sale_id,
+                vegetable_id: tomato_id,
+                quantity: Quantity::try_from(1).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn return_item_removes_detail_returned_in_full() {
+        let tomato = vegetable("トマト", 100, TaxRate::Reduced);
+        let tomato_id = tomato.id();
+        let cabbage = vegetable("キャベツ", 200, TaxRate::Reduced);
+        let cabbage_id = cabbage.id();
+        let sale = sale(vec![detail(tomato, 2), detail(cabbage, 1)]);
+
+        let sale = remaining(
+            sale.return_item(tomato_id, Quantity::try_from(2).unwrap())
+                .unwrap(),
+        );
+
+        assert_eq!(sale.sale_details().len(), 1);
+        assert_eq!(sale.sale_details()[0].vegetable().id(), cabbage_id);
+        assert_eq!(sale.total_price(), Amount::from(216u32));
+    }
+
+    #[test]
+    fn return_item_returns_fully_returned_sale_with_original_details() {
+        let tomato = vegetable("トマト", 100, TaxRate::Reduced);
+        let tomato_id = tomato.id();
+        let sale = sale(vec![detail(tomato, 2)]);
+
+        let returned = sale
+            .return_item(tomato_id, Quantity::try_from(2).unwrap())
+            .unwrap();
+
+        let ReturnedSale::FullyReturned(sale) = returned else {
+            panic!("購入した野菜がすべて返品されていません。");
+        };
+        assert_eq!(sale.sale_details().len(), 1);
+        assert_eq!(sale.sale_details()[0].sold_quantity().value(), 2);
+        assert_eq!(sale.events().len(), 2);
+    }
+
+    #[test]
+    fn return_item_rejects_quantity_over_sold_quantity() {
+        let tomato = vegetable("トマト", 100, TaxRate::Reduced);
+        let tomato_id = tomato.id();
+        let sale = sale(vec![detail(tomato, 2)]);
+
+        let result = sale.return_item(tomato_id, Quantity::try_from(3).unwrap());
+
+        assert!(matches!(result, Err(DomainError::DomainRule(_))));
+    }
+
+    #[test]
+    fn return_item_rejects_vegetable_not_purchased() {
+        let sale = sale(vec![detail(vegetable("トマト", 100, TaxRate::Reduced), 1)]);
+
+        let result = sale.return_item(VegetableId::generate(), Quantity::try_from(1).unwrap());
+
+        assert!(matches!(result, Err(DomainError::DomainRule(_))));
+    }
+
+    #[test]
+    fn stock_quantity_of_weighed_detail_is_sold_weight() {
+        let tomato = vegetable("トマト", 98, TaxRate::Reduced);
+        let tomato_id = tomato.id();
+        let unit = UnitOfMeasure::Weight(Weight::try_from(100).unwrap());
+        let weighed = SaleDetail::new(
+            tomato,
+            Price::try_from(98u32).unwrap(),
+            unit,
+            Quantity::try_from(3).unwrap(),
+            Some(Weight::try_from(350).unwrap()),
+            TaxRate::Reduced,
+        )
+        .unwrap();
+        let now = OffsetDateTime::now_utc();
+        let inventory = Inventory::reconstruct(tomato_id, 2000, now, now);
+
+        // 350gのトマトを3つ販売すると、在庫から1050gを引き当てる
+        assert_eq!(weighed.stock_quantity(), 1050);
+        let inventory = inventory
+            .allocate(Quantity::try_from(weighed.stock_quantity()).unwrap())
+            .unwrap();
+        assert_eq!(inventory.quantity(), 950);
+        assert!(matches!(
+            Inventory::reconstruct(tomato_id, 1000, now, now)
+                .allocate(Quantity::try_from(weighed.stock_quantity()).unwrap()),
+            Err(DomainError::DomainRule(_))
+        ));
+    }
+
+    #[test]
+    fn stock_quantity_of_piece_detail_is_sold_quantity() {
+        let detail = detail(vegetable("キャベツ", 150, TaxRate::Reduced), 2);
+
+        assert_eq!(detail.stock_quantity(), 2);
+    }
 }
@@ -0,0 +1,419 @@
+use std::collections::BTreeMap;
+
+use super::primitives::Amount;
+use crate::{DomainError, DomainResult};
+
+/// 消費税率
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaxRate {
+    /// 軽減税率（8%）
+    #[default]
+    Reduced,
+    /// 標準税率（10%）
+    Standard,
+}
+
+impl TaxRate {
+    /// 税率を百分率で返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 税率（百分率）
+    pub fn percentage(&self) -> u32 {
+        match self {
+            Self::Reduced => 8,
+            Self::Standard => 10,
+        }
+    }
+
+    /// 税率を表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 税率を表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Reduced => "reduced",
+            Self::Standard => "standard",
+        }
+    }
+}
+
+impl TryFrom<&str> for TaxRate {
+    type Error = DomainError;
+
+    /// 税率を表すコードから税率を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 税率を表すコード（`reduced`または`standard`）
+    ///
+    /// # 戻り値
+    ///
+    /// 税率
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "reduced" => Ok(Self::Reduced),
+            "standard" => Ok(Self::Standard),
+            _ => Err(DomainError::Validation(
+                "税率はreducedまたはstandardで指定してください。".into(),
+            )),
+        }
+    }
+}
+
+/// 価格に消費税を含めるかどうか
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TaxInclusion {
+    /// 税込価格
+    #[default]
+    Inclusive,
+    /// 税抜価格
+    Exclusive,
+}
+
+impl TaxInclusion {
+    /// 価格に消費税を含めるかどうかを表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 価格に消費税を含めるかどうかを表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Inclusive => "inclusive",
+            Self::Exclusive => "exclusive",
+        }
+    }
+}
+
+impl TryFrom<&str> for TaxInclusion {
+    type Error = DomainError;
+
+    /// コードから価格に消費税を含めるかどうかを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 価格に消費税を含めるかどうかを表すコード（`inclusive`または`exclusive`）
+    ///
+    /// # 戻り値
+    ///
+    /// 価格に消費税を含めるかどうか
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "inclusive" => Ok(Self::Inclusive),
+            "exclusive" => Ok(Self::Exclusive),
+            _ => Err(DomainError::Validation(
+                "税込価格または税抜価格はinclusiveまたはexclusiveで指定してください。".into(),
+            )),
+        }
+    }
+}
+
+/// 端数処理
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// 切り捨て
+    #[default]
+    Floor,
+    /// 四捨五入
+    Round,
+    /// 切り上げ
+    Ceil,
+}
+
+impl RoundingMode {
+    /// 端数処理を表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 端数処理を表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Floor => "floor",
+            Self::Round => "round",
+            Self::Ceil => "ceil",
+        }
+    }
+
+    /// 分数を端数処理して整数にする。
+    ///
+    /// # 引数
+    ///
+    /// * `numerator` - 分子
+    /// * `denominator` - 分母
+    ///
+    /// # 戻り値
+    ///
+    /// 端数処理した整数（計算の途中で`u64`型で表現できる範囲を超える場合、または分母が0の
+    /// 場合は`None`）
+    pub fn divide(&self, numerator: u64, denominator: u64) -> Option<u64> {
+        if denominator == 0 {
+            return None;
+        }
+        match self {
+            Self::Floor => Some(numerator / denominator),
+            Self::Round => {
+                let numerator = numerator.checked_mul(2)?.checked_add(denominator)?;
+                Some(numerator / denominator.checked_mul(2)?)
+            }
+            Self::Ceil => Some(numerator.div_ceil(denominator)),
+        }
+    }
+}
+
+impl TryFrom<&str> for RoundingMode {
+    type Error = DomainError;
+
+    /// 端数処理を表すコードから端数処理を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `value` - 端数処理を表すコード（`floor`、`round`または`ceil`）
+    ///
+    /// # 戻り値
+    ///
+    /// 端数処理
+    ///
+    /// # エラー
+    ///
+    /// `DomainError::Validation`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "floor" => Ok(Self::Floor),
+            "round" => Ok(Self::Round),
+            "ceil" => Ok(Self::Ceil),
+            _ => Err(DomainError::Validation(
+                "端数処理はfloor、roundまたはceilで指定してください。".into(),
+            )),
+        }
+    }
+}
+
+/// 消費税の計算方法
+///
+/// 適格請求書等保存方式に従い、消費税は1回の販売（請求書）につき、税率ごとに1回だけ
+/// 端数処理する。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TaxPolicy {
+    /// 価格に消費税を含めるかどうか
+    pub inclusion: TaxInclusion,
+    /// 端数処理
+    pub rounding: RoundingMode,
+}
+
+impl TaxPolicy {
+    /// 税率ごとに消費税を計算する。
+    ///
+    /// # 引数
+    ///
+    /// * `amounts` - 税率と金額の組み合わせ
+    ///
+    /// # 戻り値
+    ///
+    /// 税率の順に並べた、税率ごとの消費税
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 金額が扱える範囲を超える場合
+    pub fn calculate<I>(&self, amounts: I) -> DomainResult<Vec<TaxAmount>>
+    where
+        I: IntoIterator<Item = (TaxRate, Amount)>,
+    {
+        let mut taxable_amounts: BTreeMap<TaxRate, Amount> = BTreeMap::new();
+        for (rate, amount) in amounts {
+            let taxable_amount = taxable_amounts.entry(rate).or_default();
+            *taxable_amount = taxable_amount
+                .checked_add(amount)
+                .ok_or_else(too_large_amount)?;
+        }
+
+        taxable_amounts
+            .into_iter()
+            .map(|(rate, taxable_amount)| {
+                let percentage = rate.percentage() as u64;
+                let denominator = match self.inclusion {
+                    TaxInclusion::Inclusive => 100 + percentage,
+                    TaxInclusion::Exclusive => 100,
+                };
+                let tax_amount = taxable_amount
+                    .value()
+                    .checked_mul(percentage)
+                    .and_then(|numerator| self.rounding.divide(numerator, denominator))
+                    .ok_or_else(too_large_amount)?;
+
+                Ok(TaxAmount {
+                    rate,
+                    taxable_amount,
+                    tax_amount: tax_amount.into(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 税率ごとの消費税
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaxAmount {
+    /// 税率
+    rate: TaxRate,
+    /// 対象額（価格が税込価格の場合は消費税を含む）
+    taxable_amount: Amount,
+    /// 消費税額
+    tax_amount: Amount,
+}
+
+impl TaxAmount {
+    /// 税率を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 税率
+    pub fn rate(&self) -> TaxRate {
+        self.rate
+    }
+
+    /// 対象額を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 対象額（価格が税込価格の場合は消費税を含む）
+    pub fn taxable_amount(&self) -> Amount {
+        self.taxable_amount
+    }
+
+    /// 消費税額を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 消費税額
+    pub fn tax_amount(&self) -> Amount {
+        self.tax_amount
+    }
+}
+
+/// 金額が扱える範囲を超えたことを示すエラーを返す。
+fn too_large_amount() -> DomainError {
+    DomainError::Validation("金額が大きすぎます。".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::primitives::{Price, Quantity};
+
+    fn tax_amounts(
+        inclusion: TaxInclusion,
+        rounding: RoundingMode,
+        amounts: &[(TaxRate, u64)],
+    ) -> Vec<(TaxRate, u64)> {
+        let policy = TaxPolicy {
+            inclusion,
+            rounding,
+        };
+        policy
+            .calculate(
+                amounts
+                    .iter()
+                    .map(|(rate, amount)| (*rate, Amount::from(*amount))),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.rate(), t.tax_amount().value()))
+            .collect()
+    }
+
+    #[test]
+    fn calculate_rounds_exclusive_taxes() {
+        // 1,006円の8%は80.48円、1,019円の8%は81.52円
+        let cases = [
+            (RoundingMode::Floor, 1_006, 80),
+            (RoundingMode::Round, 1_006, 80),
+            (RoundingMode::Ceil, 1_006, 81),
+            (RoundingMode::Floor, 1_019, 81),
+            (RoundingMode::Round, 1_019, 82),
+            (RoundingMode::Ceil, 1_019, 82),
+        ];
+        for (rounding, amount, expected) in cases {
+            assert_eq!(
+                tax_amounts(
+                    TaxInclusion::Exclusive,
+                    rounding,
+                    &[(TaxRate::Reduced, amount)]
+                ),
+                vec![(TaxRate::Reduced, expected)],
+                "{rounding:?} {amount}",
+            );
+        }
+    }
+
+    #[test]
+    fn calculate_rounds_inclusive_taxes() {
+        // 1,000円に含まれる8%の消費税は74.07円、1,100円に含まれる10%の消費税は100円
+        let cases = [
+            (RoundingMode::Floor, TaxRate::Reduced, 1_000, 74),
+            (RoundingMode::Round, TaxRate::Reduced, 1_000, 74),
+            (RoundingMode::Ceil, TaxRate::Reduced, 1_000, 75),
+            (RoundingMode::Floor, TaxRate::Standard, 1_100, 100),
+            (RoundingMode::Ceil, TaxRate::Standard, 1_100, 100),
+        ];
+        for (rounding, rate, amount, expected) in cases {
+            assert_eq!(
+                tax_amounts(TaxInclusion::Inclusive, rounding, &[(rate, amount)]),
+                vec![(rate, expected)],
+                "{rounding:?} {rate:?} {amount}",
+            );
+        }
+    }
+
+    #[test]
+    fn calculate_rounds_once_per_rate() {
+        let taxes = tax_amounts(
+            TaxInclusion::Exclusive,
+            RoundingMode::Floor,
+            &[
+                (TaxRate::Standard, 105),
+                (TaxRate::Reduced, 98),
+                (TaxRate::Standard, 105),
+                (TaxRate::Reduced, 98),
+            ],
+        );
+
+        // 税率の順に並び、98円の8%を2回切り捨てた14円ではなく、196円の8%を切り捨てた15円になる
+        assert_eq!(taxes, vec![(TaxRate::Reduced, 15), (TaxRate::Standard, 21)]);
+    }
+
+    #[test]
+    fn calculate_rejects_overflow_instead_of_panicking() {
+        // 10億円の野菜を12億個販売した金額に10%を乗じて四捨五入すると、計算の途中で
+        // u64型の範囲を超える
+        let amount = Price::try_from(1_000_000_000u32).unwrap()
+            * Quantity::try_from(1_200_000_000u32).unwrap();
+        let policy = |rounding| TaxPolicy {
+            inclusion: TaxInclusion::Exclusive,
+            rounding,
+        };
+
+        assert!(matches!(
+            policy(RoundingMode::Round).calculate([(TaxRate::Standard, amount)]),
+            Err(DomainError::Validation(_))
+        ));
+        assert!(policy(RoundingMode::Floor)
+            .calculate([(TaxRate::Standard, amount)])
+            .is_ok());
+        assert!(matches!(
+            policy(RoundingMode::Floor).calculate([
+                (TaxRate::Reduced, Amount::from(u64::MAX)),
+                (TaxRate::Reduced, Amount::from(1u64)),
+            ]),
+            Err(DomainError::Validation(_))
+        ));
+    }
+}
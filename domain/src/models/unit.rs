@@ -0,0 +1,195 @@
+use super::primitives::{Price, Weight};
+use super::tax::RoundingMode;
+use crate::{DomainError, DomainResult};
+
+/// 野菜を販売する単位
+///
+/// 量り売りする野菜の単価は、単位が持つ重さあたりの価格とする。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UnitOfMeasure {
+    /// 個
+    #[default]
+    Piece,
+    /// 束
+    Bundle,
+    /// 袋
+    Bag,
+    /// 量り売り（単価の基準となる重さ）
+    Weight(Weight),
+}
+
+impl UnitOfMeasure {
+    /// 量り売りする野菜の価格の端数処理
+    ///
+    /// 量り売りする野菜の価格は、1円未満を切り捨てる。
+    pub const WEIGHED_PRICE_ROUNDING: RoundingMode = RoundingMode::Floor;
+
+    /// 単位を表すコードと単価の基準となる重さから単位を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `code` - 単位を表すコード（`piece`、`bundle`、`bag`または`weight`）
+    /// * `grams` - 量り売りする野菜の単価の基準となる重さ
+    ///
+    /// # 戻り値
+    ///
+    /// 単位
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - コードが正しくない場合、量り売りで重さが指定されて
+    ///   いない場合、または量り売り以外で重さが指定されている場合
+    pub fn from_code(code: &str, grams: Option<Weight>) -> DomainResult<Self> {
+        let unit = match (code, grams) {
+            ("weight", Some(grams)) => return Ok(Self::Weight(grams)),
+            ("weight", None) => {
+                return Err(DomainError::Validation(
+                    "量り売りの単位には、単価の基準となる重さを指定してください。".into(),
+                ))
+            }
+            ("piece", _) => Self::Piece,
+            ("bundle", _) => Self::Bundle,
+            ("bag", _) => Self::Bag,
+            _ => {
+                return Err(DomainError::Validation(
+                    "単位はpiece、bundle、bagまたはweightで指定してください。".into(),
+                ))
+            }
+        };
+        if grams.is_some() {
+            return Err(DomainError::Validation(
+                "量り売り以外の単位には、重さを指定できません。".into(),
+            ));
+        }
+
+        Ok(unit)
+    }
+
+    /// 単位を表すコードを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 単位を表すコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Piece => "piece",
+            Self::Bundle => "bundle",
+            Self::Bag => "bag",
+            Self::Weight(_) => "weight",
+        }
+    }
+
+    /// 量り売りする野菜の単価の基準となる重さを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 単価の基準となる重さ（量り売り以外の場合は`None`）
+    pub fn grams(&self) -> Option<Weight> {
+        match self {
+            Self::Weight(grams) => Some(*grams),
+            _ => None,
+        }
+    }
+
+    /// 単価と重さから、野菜1つあたりの価格を計算する。
+    ///
+    /// 量り売りの場合は、単価に重さを乗じて単価の基準となる重さで除した価格の1円未満を
+    /// 切り捨てる。量り売り以外の場合は単価とする。
+    ///
+    /// # 引数
+    ///
+    /// * `unit_price` - 単価
+    /// * `weight` - 量り売りする野菜の重さ
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜1つあたりの価格
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 量り売りで重さが指定されていない場合、量り売り以外で
+    ///   重さが指定されている場合、または価格が扱える範囲を超える場合
+    pub fn price_of(&self, unit_price: Price, weight: Option<Weight>) -> DomainResult<Price> {
+        match (self, weight) {
+            (Self::Weight(grams), Some(weight)) => {
+                let too_large = || DomainError::Validation("量り売りの価格が大きすぎます。".into());
+                let price = Self::WEIGHED_PRICE_ROUNDING
+                    .divide(
+                        unit_price.value() as u64 * weight.grams() as u64,
+                        grams.grams() as u64,
+                    )
+                    .ok_or_else(too_large)?;
+                Price::try_from(price).map_err(|_| too_large())
+            }
+            (Self::Weight(_), None) => Err(DomainError::Validation(
+                "量り売りの野菜は、重さを指定してください。".into(),
+            )),
+            (_, Some(_)) => Err(DomainError::Validation(
+                "量り売り以外の野菜には、重さを指定できません。".into(),
+            )),
+            (_, None) => Ok(unit_price),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weight(grams: u32) -> Weight {
+        Weight::try_from(grams).unwrap()
+    }
+
+    fn price(value: u32) -> Price {
+        Price::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn price_of_calculates_weighed_price() {
+        let unit = UnitOfMeasure::Weight(weight(100));
+
+        // 100gあたり98円の野菜を350g販売すると343円
+        assert_eq!(
+            unit.price_of(price(98), Some(weight(350))).unwrap(),
+            price(343)
+        );
+        // 100gあたり98円の野菜を333g販売すると326.34円で、1円未満を切り捨てる
+        assert_eq!(
+            unit.price_of(price(98), Some(weight(333))).unwrap(),
+            price(326)
+        );
+    }
+
+    #[test]
+    fn price_of_returns_unit_price_unless_weighed() {
+        for unit in [
+            UnitOfMeasure::Piece,
+            UnitOfMeasure::Bundle,
+            UnitOfMeasure::Bag,
+        ] {
+            assert_eq!(unit.price_of(price(150), None).unwrap(), price(150));
+        }
+    }
+
+    #[test]
+    fn price_of_requires_weight_only_when_weighed() {
+        assert!(matches!(
+            UnitOfMeasure::Weight(weight(100)).price_of(price(98), None),
+            Err(DomainError::Validation(_))
+        ));
+        assert!(matches!(
+            UnitOfMeasure::Piece.price_of(price(98), Some(weight(350))),
+            Err(DomainError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn price_of_rejects_too_large_price() {
+        let unit = UnitOfMeasure::Weight(weight(1));
+
+        assert!(matches!(
+            unit.price_of(price(Price::MAX), Some(weight(2))),
+            Err(DomainError::Validation(_))
+        ));
+    }
+}
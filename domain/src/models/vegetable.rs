@@ -1,7 +1,15 @@
-use time::OffsetDateTime;
+use std::collections::BTreeSet;
+
+use time::{Month, OffsetDateTime};
 use uuid::Uuid;
 
-use super::primitives::Price;
+use super::availability::AvailableMonths;
+use super::category::CategoryId;
+use super::events::DomainEvent;
+use super::primitives::{Price, Tag, VegetableName};
+use super::tax::TaxRate;
+use super::unit::UnitOfMeasure;
+use crate::{DomainError, DomainResult};
 use macros::EntityId;
 
 /// 野菜ID
@@ -15,16 +23,59 @@ pub struct Vegetable {
     /// 野菜ID
     id: VegetableId,
     /// 野菜名
-    name: String,
-    /// 単価
+    name: VegetableName,
+    /// 単価（量り売りの場合は、単位が持つ重さあたりの価格）
     unit_price: Price,
+    /// 消費税率
+    tax_rate: TaxRate,
+    /// 販売する単位
+    unit: UnitOfMeasure,
+    /// 野菜を分類するカテゴリのカテゴリID
+    category_id: Option<CategoryId>,
+    /// タグ
+    tags: Vec<Tag>,
+    /// 販売する月
+    available_months: AvailableMonths,
+    /// 販売中かどうか（販売を終了した野菜は`false`）
+    active: bool,
+    /// 版（野菜を更新するたびに1ずつ増える）
+    version: u32,
     /// 作成日時
     created_at: OffsetDateTime,
     /// 更新日時
     updated_at: OffsetDateTime,
+    /// 発行したドメインイベント
+    events: Vec<DomainEvent>,
 }
 
 impl Vegetable {
+    /// 野菜に付けられるタグの最大数
+    pub const MAX_TAGS: usize = 10;
+
+    /// 野菜に付けるタグから重複を取り除いて、タグの順に並べる。
+    ///
+    /// # 引数
+    ///
+    /// * `tags` - 野菜に付けるタグ
+    ///
+    /// # 戻り値
+    ///
+    /// 重複を取り除いてタグの順に並べたタグのベクタ
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Validation` - 重複を取り除いたタグの数が最大数を超える場合
+    pub fn arrange_tags(tags: impl IntoIterator<Item = Tag>) -> DomainResult<Vec<Tag>> {
+        let tags: BTreeSet<Tag> = tags.into_iter().collect();
+        if Self::MAX_TAGS < tags.len() {
+            return Err(DomainError::Validation(
+                format!("タグは{}個以下で指定してください。", Self::MAX_TAGS).into(),
+            ));
+        }
+
+        Ok(tags.into_iter().collect())
+    }
+
     /// 野菜を構築する。
     ///
     /// # 引数
@@ -32,25 +83,48 @@ impl Vegetable {
     /// * `id` - 野菜ID
     /// * `name` - 野菜名
     /// * `unit_price` - 単価
+    /// * `tax_rate` - 消費税率
+    /// * `unit` - 販売する単位
+    /// * `category_id` - 野菜を分類するカテゴリのカテゴリID
+    /// * `tags` - タグ
+    /// * `available_months` - 販売する月
+    /// * `active` - 販売中かどうか
+    /// * `version` - 版
     /// * `created_at` - 作成日時
     /// * `updated_at` - 更新日時
     ///
     /// # 戻り値
     ///
     /// 野菜
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: VegetableId,
-        name: &str,
+        name: VegetableName,
         unit_price: Price,
+        tax_rate: TaxRate,
+        unit: UnitOfMeasure,
+        category_id: Option<CategoryId>,
+        tags: Vec<Tag>,
+        available_months: AvailableMonths,
+        active: bool,
+        version: u32,
         created_at: OffsetDateTime,
         updated_at: OffsetDateTime,
     ) -> Self {
         Self {
             id,
-            name: name.to_string(),
+            name,
             unit_price,
+            tax_rate,
+            unit,
+            category_id,
+            tags,
+            available_months,
+            active,
+            version,
             created_at,
             updated_at,
+            events: Vec::new(),
         }
     }
 
@@ -68,7 +142,7 @@ impl Vegetable {
     /// # 戻り値
     ///
     /// 野菜名
-    pub fn name(&self) -> &str {
+    pub fn name(&self) -> &VegetableName {
         &self.name
     }
 
@@ -81,6 +155,123 @@ impl Vegetable {
         self.unit_price
     }
 
+    /// 消費税率を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 消費税率
+    pub fn tax_rate(&self) -> TaxRate {
+        self.tax_rate
+    }
+
+    /// 販売する単位を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売する単位
+    pub fn unit(&self) -> UnitOfMeasure {
+        self.unit
+    }
+
+    /// 野菜を分類するカテゴリのカテゴリIDを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリID（カテゴリに分類されていない場合は`None`）
+    pub fn category_id(&self) -> Option<CategoryId> {
+        self.category_id
+    }
+
+    /// タグを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// タグのスライス
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// 販売する月を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売する月
+    pub fn available_months(&self) -> AvailableMonths {
+        self.available_months
+    }
+
+    /// 販売中かどうかを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売中の場合は`true`、販売を終了した場合は`false`
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    /// 版を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 版
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// 版が、指定した版と一致するか確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `version` - 版（`None`の場合は版を確認しない）
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Conflict` - 版が一致しない場合
+    pub fn ensure_version(&self, version: Option<u32>) -> DomainResult<()> {
+        match version {
+            Some(version) if version != self.version => Err(version_conflict_error()),
+            _ => Ok(()),
+        }
+    }
+
+    /// 指定した月に、野菜を販売できるか確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `month` - 野菜を販売する月
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::DomainRule` - 販売を終了した野菜の場合、または販売する月に指定した月が
+    ///   含まれない野菜の場合
+    pub fn ensure_available(&self, month: Month) -> DomainResult<()> {
+        if !self.active {
+            return Err(DomainError::DomainRule(
+                format!("販売を終了した野菜です。(野菜ID: {})", self.id.value()).into(),
+            ));
+        }
+        if !self.available_months.contains(month) {
+            return Err(DomainError::DomainRule(
+                format!(
+                    "{}月は販売していない野菜です。(野菜ID: {})",
+                    u8::from(month),
+                    self.id.value()
+                )
+                .into(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// 作成日時を返す。
     ///
     /// # 戻り値
@@ -98,4 +289,137 @@ impl Vegetable {
     pub fn updated_at(&self) -> OffsetDateTime {
         self.updated_at
     }
+
+    /// 野菜の価格を変更する。
+    ///
+    /// 適用開始日時が現在以前の価格の場合は、野菜の単価を変更する。適用開始日時が未来の
+    /// 価格の場合は、野菜の単価を変更せずに、適用開始日時に単価が変わることを示す
+    /// ドメインイベントを発行する。現在の単価と同じ単価を現在から適用する場合は、
+    /// ドメインイベントを発行しない。
+    ///
+    /// # 引数
+    ///
+    /// * `price` - 変更後の野菜の価格
+    ///
+    /// # 戻り値
+    ///
+    /// 価格を変更した場合は`true`、現在の単価と同じ単価を現在から適用する場合は`false`
+    pub fn change_price(&mut self, price: VegetablePrice) -> bool {
+        let effective = price.effective_from() <= OffsetDateTime::now_utc();
+        if effective && price.unit_price() == self.unit_price {
+            return false;
+        }
+        self.events.push(DomainEvent::VegetablePriceChanged {
+            vegetable_id: self.id,
+            previous_unit_price: self.unit_price,
+            unit_price: price.unit_price(),
+            effective_from: price.effective_from(),
+        });
+        if effective {
+            self.unit_price = price.unit_price();
+        }
+
+        true
+    }
+
+    /// 発行したドメインイベントを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 発行した順に並べたドメインイベントのスライス
+    pub fn events(&self) -> &[DomainEvent] {
+        &self.events
+    }
+}
+
+/// 野菜の版が一致しなかった時のエラーを返す。
+///
+/// # 戻り値
+///
+/// `DomainError::Conflict`
+pub fn version_conflict_error() -> DomainError {
+    DomainError::Conflict(
+        "野菜は他の利用者によって更新されています。最新の野菜を取得してから再度実行してください。"
+            .into(),
+    )
+}
+
+/// 野菜の価格
+///
+/// 野菜の単価と、その単価を適用する日時を持つ値オブジェクトである。野菜の単価は、
+/// 適用開始日時が現在以前の価格のうち、適用開始日時が最も新しい価格の単価とする。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VegetablePrice {
+    /// 単価
+    unit_price: Price,
+    /// 適用開始日時
+    effective_from: OffsetDateTime,
+}
+
+impl VegetablePrice {
+    /// 野菜の価格を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `unit_price` - 単価
+    /// * `effective_from` - 適用開始日時
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の価格
+    pub fn new(unit_price: Price, effective_from: OffsetDateTime) -> Self {
+        Self {
+            unit_price,
+            effective_from,
+        }
+    }
+
+    /// 現在以降に適用する野菜の価格を構築する。
+    ///
+    /// 適用開始日時を指定しない場合は、現在から適用する。
+    ///
+    /// # 引数
+    ///
+    /// * `unit_price` - 単価
+    /// * `effective_from` - 適用開始日時
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の価格
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::DomainRule` - 適用開始日時が過去の場合
+    pub fn schedule(
+        unit_price: Price,
+        effective_from: Option<OffsetDateTime>,
+    ) -> DomainResult<Self> {
+        let now = OffsetDateTime::now_utc();
+        let effective_from = effective_from.unwrap_or(now);
+        if effective_from < now {
+            return Err(DomainError::DomainRule(
+                "過去の日時から適用する価格は登録できません。".into(),
+            ));
+        }
+
+        Ok(Self::new(unit_price, effective_from))
+    }
+
+    /// 単価を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 単価
+    pub fn unit_price(&self) -> Price {
+        self.unit_price
+    }
+
+    /// 適用開始日時を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 適用開始日時
+    pub fn effective_from(&self) -> OffsetDateTime {
+        self.effective_from
+    }
 }
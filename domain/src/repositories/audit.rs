@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::models::audit::{AuditEntityType, AuditLog};
+use crate::DomainResult;
+
+/// 監査ログの検索条件
+#[derive(Clone, Debug)]
+pub struct AuditLogQuery {
+    /// 変更したエンティティの種類
+    pub entity_type: Option<AuditEntityType>,
+    /// 変更したエンティティのID
+    pub entity_id: Option<Uuid>,
+    /// 取得する監査ログの数
+    pub limit: u32,
+}
+
+impl AuditLogQuery {
+    /// 取得する監査ログの既定の数
+    pub const DEFAULT_LIMIT: u32 = 100;
+    /// 取得する監査ログの最大数
+    pub const MAX_LIMIT: u32 = 1000;
+}
+
+impl Default for AuditLogQuery {
+    fn default() -> Self {
+        Self {
+            entity_type: None,
+            entity_id: None,
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+}
+
+/// 監査ログリポジトリ
+///
+/// 監査ログは、野菜リポジトリと販売リポジトリが、野菜や販売の変更と同一のトランザクションで
+/// 記録する。監査ログリポジトリは、記録された監査ログを検索する。
+#[async_trait]
+pub trait AuditLogRepository: 'static {
+    /// 検索条件に一致する監査ログを、記録した日時の新しい順に検索する。
+    async fn find_all(&self, query: AuditLogQuery) -> DomainResult<Vec<AuditLog>>;
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::models::category::{Category, CategoryId};
+use crate::models::primitives::CategoryName;
+use crate::DomainResult;
+
+/// 登録または更新するカテゴリ
+pub struct UpsertCategory {
+    /// カテゴリ名
+    pub name: CategoryName,
+}
+
+/// カテゴリリポジトリ
+#[async_trait]
+pub trait CategoryRepository: 'static {
+    /// カテゴリIDで指定したカテゴリを検索する。
+    async fn find_by_id(&self, id: CategoryId) -> DomainResult<Option<Category>>;
+
+    /// すべてのカテゴリを検索する。
+    async fn find_all(&self) -> DomainResult<Vec<Category>>;
+
+    /// カテゴリを登録する。
+    ///
+    /// 同じ名前のカテゴリが既に登録されている場合は、`DomainError::DomainRule`を返す。
+    async fn register(&self, category: UpsertCategory) -> DomainResult<Category>;
+
+    /// カテゴリを更新する。
+    ///
+    /// 同じ名前のカテゴリが既に登録されている場合は、`DomainError::DomainRule`を返す。
+    async fn update(
+        &self,
+        id: CategoryId,
+        category: UpsertCategory,
+    ) -> DomainResult<Option<Category>>;
+
+    /// カテゴリIDで指定したカテゴリを削除する。
+    ///
+    /// 削除したカテゴリに分類されていた野菜は、カテゴリに分類されていない野菜になる。
+    async fn delete(&self, id: CategoryId) -> DomainResult<u32>;
+}
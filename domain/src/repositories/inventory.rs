@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+
+use crate::models::inventory::Inventory;
+use crate::models::primitives::Quantity;
+use crate::models::vegetable::VegetableId;
+use crate::DomainResult;
+
+/// 在庫リポジトリ
+///
+/// 販売による在庫の増減は、販売と同一のトランザクションで行うため、販売リポジトリが
+/// 担当する。
+#[async_trait]
+pub trait InventoryRepository: 'static {
+    /// 野菜IDで指定した野菜の在庫を検索する。
+    async fn find_by_vegetable_id(
+        &self,
+        vegetable_id: VegetableId,
+    ) -> DomainResult<Option<Inventory>>;
+
+    /// 在庫を管理しているすべての野菜の在庫を検索する。
+    async fn find_all(&self) -> DomainResult<Vec<Inventory>>;
+
+    /// 野菜を入荷する。
+    ///
+    /// 在庫を管理していない野菜の場合は、在庫の管理を開始する。野菜が存在しない場合は
+    /// `None`を返す。
+    async fn receive(
+        &self,
+        vegetable_id: VegetableId,
+        quantity: Quantity,
+    ) -> DomainResult<Option<Inventory>>;
+
+    /// 傷んだ野菜などを廃棄する。
+    ///
+    /// 廃棄する数量が在庫数を超える場合は、`DomainError::DomainRule`を返す。在庫を
+    /// 管理していない野菜の場合は`None`を返す。
+    async fn dispose(
+        &self,
+        vegetable_id: VegetableId,
+        quantity: Quantity,
+    ) -> DomainResult<Option<Inventory>>;
+
+    /// 野菜IDで指定した野菜の在庫を削除して、在庫の管理を終了する。
+    ///
+    /// 在庫の管理を終了した野菜は、在庫が無限にあるものとして扱う。
+    async fn delete(&self, vegetable_id: VegetableId) -> DomainResult<u32>;
+}
@@ -1,13 +1,8 @@
+pub mod audit;
+pub mod category;
+pub mod inventory;
+pub mod outbox;
+pub mod report;
+pub mod sale;
+pub mod unit_of_work;
 pub mod vegetable;
-
-use self::vegetable::VegetableRepository;
-
-/// リポジトリコンテナ
-#[derive(Clone, Debug)]
-pub struct RepositoryContainer<VR>
-where
-    VR: VegetableRepository,
-{
-    /// 野菜リポジトリ
-    pub vegetable: VR,
-}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+use crate::models::events::PendingEvent;
+use crate::DomainResult;
+
+/// アウトボックスリポジトリ
+///
+/// ドメインイベントは、野菜リポジトリと販売リポジトリが、集約の変更と同一のトランザクションで
+/// アウトボックスに記録する。アウトボックスリポジトリは、記録されたドメインイベントのうち、
+/// 配信していないドメインイベントを検索して、配信した結果を記録する。
+#[async_trait]
+pub trait OutboxRepository: 'static {
+    /// 配信していないドメインイベントを、記録した順に検索する。
+    ///
+    /// 配信を試みた回数が、指定した回数に達したドメインイベントは検索しない。
+    /// ドメインイベントに変換できなかったドメインイベントは、エラーとせずに
+    /// `PendingEvent::Undecodable`として返す。
+    async fn find_pending(&self, limit: u32, max_attempts: u32) -> DomainResult<Vec<PendingEvent>>;
+
+    /// ドメインイベントを配信したことを記録する。
+    async fn mark_processed(&self, id: u64) -> DomainResult<()>;
+
+    /// ドメインイベントの配信に失敗したことを記録して、配信を試みた回数を1増やす。
+    async fn mark_failed(&self, id: u64, error: &str) -> DomainResult<()>;
+}
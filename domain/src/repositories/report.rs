@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::models::primitives::VegetableName;
+use crate::models::vegetable::VegetableId;
+use crate::DomainResult;
+
+/// 野菜ごとの売上
+///
+/// 個、束または袋で販売した数量と、量り売りで販売した重さは単位が異なるため、別々に集計する。
+#[derive(Clone, Debug)]
+pub struct VegetableSales {
+    /// 野菜ID
+    pub vegetable_id: VegetableId,
+    /// 野菜名
+    pub vegetable_name: VegetableName,
+    /// 個、束または袋で販売した数量（量り売りで販売した野菜は含まない）
+    pub quantity: u64,
+    /// 量り売りで販売した重さ（グラム、量り売りで販売していない場合は`None`）
+    pub weight: Option<u64>,
+    /// 売上金額（販売明細の小計の合計）
+    pub revenue: u64,
+}
+
+/// 期間の売上
+///
+/// 売上金額は、販売明細の小計を合計した金額とする。販売明細の小計は販売の消費税の計算方法に
+/// 従い、税込価格の場合は消費税を含み、税抜価格の場合は消費税を含まない。期間の売上金額と
+/// 野菜ごとの売上金額は同じ基準で集計するため、野菜ごとの売上金額の合計は期間の売上金額と
+/// 一致する。
+#[derive(Clone, Debug)]
+pub struct SalesSummary {
+    /// 販売数
+    pub number_of_sales: u64,
+    /// 売上金額（販売明細の小計の合計）
+    pub total_revenue: u64,
+    /// 野菜ごとの売上（売上金額の降順）
+    pub vegetables: Vec<VegetableSales>,
+}
+
+/// 売上集計リポジトリ
+///
+/// 販売を読み込まずに、永続化層で売上を集計する。
+#[async_trait]
+pub trait ReportRepository: 'static {
+    /// 販売日時が指定した期間（開始日時を含み、終了日時を含まない）の売上を集計する。
+    ///
+    /// 野菜ごとの売上は、売上金額の降順、野菜名の順に並べる。
+    async fn summarize_sales(
+        &self,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> DomainResult<SalesSummary>;
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+
+use crate::models::primitives::Actor;
+use crate::models::sales::{Sale, SaleId};
+use crate::DomainResult;
+
+/// 販売リポジトリ
+///
+/// 在庫を管理している野菜の在庫は、販売の登録、販売明細の置き換え及び販売の削除と同一の
+/// トランザクションで増減させる。在庫が不足する場合は、`DomainError::DomainRule`を返す。
+///
+/// 販売を変更するときは、変更した利用者と、変更前後の販売を、同一のトランザクションで
+/// 監査ログに記録する。また、販売が発行したドメインイベントを、同一のトランザクションで
+/// アウトボックスに記録する。
+#[async_trait]
+pub trait SaleRepository: 'static {
+    /// 販売IDで指定した販売を検索する。
+    async fn find_by_id(&self, id: SaleId) -> DomainResult<Option<Sale>>;
+
+    /// 販売IDで指定した販売を排他ロックして検索する。
+    ///
+    /// 販売を変更するために検索するときに、ユニットオブワークのリポジトリで使用する。
+    /// ロックはユニットオブワークをコミットまたはロールバックするまで保持されるため、
+    /// 同じ販売を並行して変更できない。トランザクションを持つ実装は、ユニットオブワークに
+    /// 参加していないリポジトリで呼び出した場合、ロックを保持できないためエラーを返す。
+    async fn find_by_id_for_update(&self, id: SaleId) -> DomainResult<Option<Sale>>;
+
+    /// すべての販売を検索する。
+    async fn find_all(&self) -> DomainResult<Vec<Sale>>;
+
+    /// 販売を登録する。
+    async fn register(&self, sale: Sale, actor: &Actor) -> DomainResult<Sale>;
+
+    /// 販売の販売明細を置き換える。
+    ///
+    /// 販売明細は値オブジェクトであるため、既存の販売明細をすべて削除して、
+    /// 引数の販売が持つ販売明細を新たに登録する。
+    async fn replace_details(&self, sale: Sale, actor: &Actor) -> DomainResult<Option<Sale>>;
+
+    /// 販売を削除する。
+    async fn delete(&self, sale: &Sale, actor: &Actor) -> DomainResult<u32>;
+}
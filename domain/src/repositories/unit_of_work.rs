@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+
+use super::inventory::InventoryRepository;
+use super::sale::SaleRepository;
+use super::vegetable::VegetableRepository;
+use crate::DomainResult;
+
+/// ユニットオブワーク
+///
+/// 1つのトランザクションに参加するリポジトリを提供して、それらのリポジトリによる変更を
+/// まとめてコミットまたはロールバックする。コミットせずに破棄したユニットオブワークの
+/// 変更は、ロールバックされる。
+///
+/// ユニットオブワークのトランザクションは、他のトランザクションがコミットした変更を
+/// 読み込むため、検索した集約に基づいて変更する場合は、検索から変更までの間に他の
+/// トランザクションが集約を変更しないように、集約をロックして検索するリポジトリのメソッド
+/// （`find_by_id_for_update`など）で集約を検索する。
+///
+/// リポジトリがエラーを返した場合、トランザクションを継続できないことがあるため、
+/// ユニットオブワークをコミットせずに破棄する。
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// トランザクションに参加する野菜リポジトリの型
+    type Vegetable: VegetableRepository + Send + Sync;
+
+    /// トランザクションに参加する販売リポジトリの型
+    type Sale: SaleRepository + Send + Sync;
+
+    /// トランザクションに参加する在庫リポジトリの型
+    type Inventory: InventoryRepository + Send + Sync;
+
+    /// トランザクションに参加する野菜リポジトリを返す。
+    fn vegetable(&self) -> &Self::Vegetable;
+
+    /// トランザクションに参加する販売リポジトリを返す。
+    fn sale(&self) -> &Self::Sale;
+
+    /// トランザクションに参加する在庫リポジトリを返す。
+    fn inventory(&self) -> &Self::Inventory;
+
+    /// リポジトリによる変更をまとめてコミットする。
+    async fn commit(self) -> DomainResult<()>;
+
+    /// リポジトリによる変更をまとめてロールバックする。
+    async fn rollback(self) -> DomainResult<()>;
+}
+
+/// ユニットオブワークファクトリ
+#[async_trait]
+pub trait UnitOfWorkFactory: 'static {
+    /// ファクトリが開始するユニットオブワークの型
+    type UnitOfWork: UnitOfWork;
+
+    /// トランザクションを開始して、ユニットオブワークを返す。
+    async fn begin(&self) -> DomainResult<Self::UnitOfWork>;
+}
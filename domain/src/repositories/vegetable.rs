@@ -1,51 +1,275 @@
 use async_trait::async_trait;
+use time::Month;
 
-use crate::models::primitives::Price;
-use crate::models::vegetable::{Vegetable, VegetableId};
+use crate::models::availability::AvailableMonths;
+use crate::models::category::CategoryId;
+use crate::models::primitives::{Actor, Price, Tag, VegetableName};
+use crate::models::tax::TaxRate;
+use crate::models::unit::UnitOfMeasure;
+use crate::models::vegetable::{Vegetable, VegetableId, VegetablePrice};
 use crate::DomainResult;
 
 /// 登録または更新する野菜
 pub struct UpsertVegetable {
     /// 野菜名
-    pub name: String,
-    /// 単価
+    pub name: VegetableName,
+    /// 単価（現在から適用する）
     pub unit_price: Price,
+    /// 消費税率
+    pub tax_rate: TaxRate,
+    /// 販売する単位
+    pub unit: UnitOfMeasure,
+    /// 野菜を分類するカテゴリのカテゴリID（カテゴリに分類しない場合は`None`）
+    pub category_id: Option<CategoryId>,
+    /// タグ
+    pub tags: Vec<Tag>,
+    /// 販売する月
+    pub available_months: AvailableMonths,
+    /// 販売中かどうか
+    pub active: bool,
 }
 
 /// 部分更新する野菜
 pub struct PartialVegetable {
     /// 野菜名
-    pub name: Option<String>,
-    /// 単価
+    pub name: Option<VegetableName>,
+    /// 単価（現在から適用する）
     pub unit_price: Option<Price>,
+    /// 消費税率
+    pub tax_rate: Option<TaxRate>,
+    /// 販売する単位
+    pub unit: Option<UnitOfMeasure>,
+    /// 野菜を分類するカテゴリのカテゴリID（`Some(None)`の場合はカテゴリへの分類を解除する）
+    pub category_id: Option<Option<CategoryId>>,
+    /// タグ（指定した場合はすべてのタグを置き換える）
+    pub tags: Option<Vec<Tag>>,
+    /// 販売する月
+    pub available_months: Option<AvailableMonths>,
+    /// 販売中かどうか
+    pub active: Option<bool>,
+}
+
+/// 野菜を並び替える項目
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VegetableSortKey {
+    /// 野菜名
+    #[default]
+    Name,
+    /// 単価
+    UnitPrice,
+    /// 作成日時
+    CreatedAt,
+}
+
+/// 並び順
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// 昇順
+    #[default]
+    Asc,
+    /// 降順
+    Desc,
+}
+
+/// 野菜の検索条件
+#[derive(Clone, Debug)]
+pub struct VegetableQuery {
+    /// 取得するページ（1から始まる）
+    pub page: u32,
+    /// 1ページに含める野菜の数
+    pub limit: u32,
+    /// 野菜名に含まれる文字列
+    pub name: Option<String>,
+    /// 単価の下限
+    pub min_unit_price: Option<Price>,
+    /// 単価の上限
+    pub max_unit_price: Option<Price>,
+    /// 野菜を分類するカテゴリのカテゴリID
+    pub category_id: Option<CategoryId>,
+    /// 野菜に付けられたタグ
+    pub tag: Option<Tag>,
+    /// 販売できる月（指定した場合は、販売中で、販売する月に指定した月が含まれる野菜のみ
+    /// 検索する）
+    pub available_in: Option<Month>,
+    /// 野菜を並び替える項目
+    pub sort_key: VegetableSortKey,
+    /// 並び順
+    pub sort_order: SortOrder,
+}
+
+impl VegetableQuery {
+    /// 1ページに含める野菜の既定の数
+    pub const DEFAULT_LIMIT: u32 = 20;
+    /// 1ページに含める野菜の最大数
+    pub const MAX_LIMIT: u32 = 100;
+
+    /// 取得するページの前にある野菜の数を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 取得するページの前にある野菜の数
+    pub fn offset(&self) -> u64 {
+        (self.page.max(1) as u64 - 1) * self.limit as u64
+    }
+}
+
+impl Default for VegetableQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            limit: Self::DEFAULT_LIMIT,
+            name: None,
+            min_unit_price: None,
+            max_unit_price: None,
+            category_id: None,
+            tag: None,
+            available_in: None,
+            sort_key: VegetableSortKey::default(),
+            sort_order: SortOrder::default(),
+        }
+    }
+}
+
+/// 野菜の検索結果
+pub struct VegetablePage {
+    /// 検索条件で指定したページの野菜
+    pub items: Vec<Vegetable>,
+    /// 検索条件に一致した野菜の総数
+    pub total: u64,
+    /// 次のページ（次のページがない場合は`None`）
+    pub next_page: Option<u32>,
+}
+
+impl VegetablePage {
+    /// 野菜の検索結果を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `query` - 野菜の検索条件
+    /// * `items` - 検索条件で指定したページの野菜
+    /// * `total` - 検索条件に一致した野菜の総数
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の検索結果
+    pub fn new(query: &VegetableQuery, items: Vec<Vegetable>, total: u64) -> Self {
+        let next_page = if query.offset() + (items.len() as u64) < total {
+            Some(query.page.max(1) + 1)
+        } else {
+            None
+        };
+
+        Self {
+            items,
+            total,
+            next_page,
+        }
+    }
 }
 
 /// 野菜リポジトリ
+///
+/// 論理削除した野菜は、野菜を復元するまで検索、更新及び価格の登録の対象としない。
+/// ただし、過去の販売明細は論理削除した野菜を参照できる。
+///
+/// 野菜を更新、論理削除若しくは復元するとき、または野菜の価格を登録するときは、野菜の版を
+/// 1増やす。版を指定して野菜を更新または削除する場合に、野菜の版が指定した版と一致しない
+/// ときは、`DomainError::Conflict`を返す。
+///
+/// 野菜を変更するときは、変更した利用者と、変更前後の野菜を、同一のトランザクションで
+/// 監査ログに記録する。また、野菜の価格を変更したときは、野菜が発行したドメインイベントを、
+/// 同一のトランザクションでアウトボックスに記録する。
 #[async_trait]
 pub trait VegetableRepository: 'static {
     /// 野菜IDで指定した野菜を検索する。
     async fn find_by_id(&self, id: VegetableId) -> DomainResult<Option<Vegetable>>;
 
-    /// すべての野菜を検索する。
-    async fn find_all(&self) -> DomainResult<Vec<Vegetable>>;
+    /// 野菜IDで指定した野菜を共有ロックして検索する。
+    ///
+    /// 野菜を販売するときなど、検索した野菜に基づいて他の集約を変更するときに、ユニット
+    /// オブワークのリポジトリで使用する。ロックはユニットオブワークをコミットまたは
+    /// ロールバックするまで保持されるため、その間に野菜は変更されない。トランザクションを
+    /// 持つ実装は、ユニットオブワークに参加していないリポジトリで呼び出した場合、ロックを
+    /// 保持できないためエラーを返す。
+    async fn find_by_id_for_share(&self, id: VegetableId) -> DomainResult<Option<Vegetable>>;
+
+    /// 検索条件に一致する野菜を検索する。
+    async fn find_all(&self, query: VegetableQuery) -> DomainResult<VegetablePage>;
 
     /// 野菜を登録する。
-    async fn register(&self, vegetable: UpsertVegetable) -> DomainResult<Vegetable>;
+    ///
+    /// 同じ野菜名の野菜が既に登録されている場合は、`DomainError::DomainRule`を返す。
+    /// 野菜を分類するカテゴリが存在しない場合は、`DomainError::Validation`を返す。
+    async fn register(&self, vegetable: UpsertVegetable, actor: &Actor) -> DomainResult<Vegetable>;
 
     /// 野菜を更新する。
+    ///
+    /// 単価は、現在から適用する価格として価格の履歴に記録する。
+    /// 同じ野菜名の他の野菜が既に登録されている場合は、`DomainError::DomainRule`を返す。
+    /// 野菜を分類するカテゴリが存在しない場合は、`DomainError::Validation`を返す。
     async fn update(
         &self,
         id: VegetableId,
+        version: Option<u32>,
         vegetable: UpsertVegetable,
+        actor: &Actor,
     ) -> DomainResult<Option<Vegetable>>;
 
     /// 野菜を部分更新する。
+    ///
+    /// 単価が指定された場合は、現在から適用する価格として価格の履歴に記録する。
+    /// 同じ野菜名の他の野菜が既に登録されている場合は、`DomainError::DomainRule`を返す。
+    /// 野菜を分類するカテゴリが存在しない場合は、`DomainError::Validation`を返す。
     async fn partial_update(
         &self,
         id: VegetableId,
+        version: Option<u32>,
         vegetable: PartialVegetable,
+        actor: &Actor,
     ) -> DomainResult<Option<Vegetable>>;
 
-    /// 野菜IDで指定した野菜を削除する。
-    async fn delete(&self, id: VegetableId) -> DomainResult<u32>;
+    /// 野菜IDで指定した野菜の価格の履歴を、適用開始日時の順に検索する。
+    ///
+    /// 適用開始日時が未来の価格も含む。野菜が存在しない場合は`None`を返す。
+    async fn find_prices(&self, id: VegetableId) -> DomainResult<Option<Vec<VegetablePrice>>>;
+
+    /// 野菜IDで指定した野菜の価格を登録する。
+    ///
+    /// 同じ適用開始日時の価格が既に登録されている場合は、その価格の単価を置き換える。
+    /// 野菜が存在しない場合は`None`を返す。
+    async fn register_price(
+        &self,
+        id: VegetableId,
+        price: VegetablePrice,
+        actor: &Actor,
+    ) -> DomainResult<Option<VegetablePrice>>;
+
+    /// 野菜IDで指定した野菜を論理削除する。
+    ///
+    /// 既に論理削除した野菜は、削除した野菜の数に含めない。
+    async fn delete(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        actor: &Actor,
+    ) -> DomainResult<u32>;
+
+    /// 野菜IDで指定した、論理削除した野菜を復元する。
+    ///
+    /// 論理削除していない野菜の場合は、野菜を変更せずに返す。野菜が存在しない場合は`None`を
+    /// 返す。同じ野菜名の他の野菜が既に登録されている場合は、`DomainError::DomainRule`を
+    /// 返す。
+    async fn restore(&self, id: VegetableId, actor: &Actor) -> DomainResult<Option<Vegetable>>;
+
+    /// 野菜IDで指定した野菜を物理削除する。
+    ///
+    /// 論理削除した野菜も物理削除する。販売明細が野菜を参照している場合は、
+    /// `DomainError::DomainRule`を返す。販売を記録しない実装も、販売明細が参照している
+    /// ことを記録した野菜は物理削除しない。
+    async fn purge(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        actor: &Actor,
+    ) -> DomainResult<u32>;
 }
@@ -1 +1,3 @@
+#[cfg(feature = "memory")]
+pub mod memory;
 pub mod postgres;
@@ -0,0 +1,5 @@
+use crate::memory::repositories::vegetable::MemoryVegetableRepository;
+use usecase::interactors::vegetable::VegetableInteractorImpl;
+
+/// インメモリの野菜インタラクター
+pub type MemoryVegetableInteractor = VegetableInteractorImpl<MemoryVegetableRepository>;
@@ -0,0 +1,2 @@
+pub mod interactors;
+pub mod repositories;
@@ -0,0 +1,643 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::models::availability::AvailableMonths;
+use domain::models::category::CategoryId;
+use domain::models::primitives::{Actor, Price, Tag, VegetableName};
+use domain::models::tax::TaxRate;
+use domain::models::unit::UnitOfMeasure;
+use domain::models::vegetable::{version_conflict_error, Vegetable, VegetableId, VegetablePrice};
+use domain::repositories::vegetable::{
+    PartialVegetable, SortOrder, UpsertVegetable, VegetablePage, VegetableQuery,
+    VegetableRepository, VegetableSortKey,
+};
+use domain::{DomainError, DomainResult};
+
+/// メモリに記録する野菜
+#[derive(Clone, Debug)]
+struct VegetableRecord {
+    id: Uuid,
+    name: VegetableName,
+    /// 適用開始日時の順に並べた価格の履歴
+    prices: Vec<VegetablePrice>,
+    tax_rate: TaxRate,
+    unit: UnitOfMeasure,
+    category_id: Option<CategoryId>,
+    tags: Vec<Tag>,
+    available_months: AvailableMonths,
+    active: bool,
+    version: u32,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+    /// 論理削除した日時（論理削除していない場合は`None`）
+    deleted_at: Option<OffsetDateTime>,
+    /// 販売明細が参照しているかどうか
+    referenced_by_sales: bool,
+}
+
+impl VegetableRecord {
+    /// 指定した日時の単価を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `at` - 日時
+    ///
+    /// # 戻り値
+    ///
+    /// 適用開始日時が指定した日時以前の価格のうち、適用開始日時が最も新しい価格の単価
+    fn unit_price(&self, at: OffsetDateTime) -> Price {
+        // 野菜を登録した時に価格を記録するため、適用されている価格は必ず存在する
+        self.prices
+            .iter()
+            .rev()
+            .find(|p| p.effective_from() <= at)
+            .unwrap_or(&self.prices[0])
+            .unit_price()
+    }
+
+    /// 価格を記録する。
+    ///
+    /// 同じ適用開始日時の価格が既に記録されている場合は、その価格を置き換える。
+    ///
+    /// # 引数
+    ///
+    /// * `price` - 記録する価格
+    fn upsert_price(&mut self, price: VegetablePrice) {
+        match self
+            .prices
+            .binary_search_by_key(&price.effective_from(), |p| p.effective_from())
+        {
+            Ok(index) => self.prices[index] = price,
+            Err(index) => self.prices.insert(index, price),
+        }
+    }
+
+    /// 現在から適用する単価を記録する。
+    ///
+    /// 現在の単価と同じ単価の場合は、価格を記録しない。
+    ///
+    /// # 引数
+    ///
+    /// * `unit_price` - 単価
+    /// * `now` - 現在の日時
+    fn change_unit_price(&mut self, unit_price: Price, now: OffsetDateTime) {
+        let price = VegetablePrice::new(unit_price, now);
+        if self.to_vegetable(now).change_price(price) {
+            self.upsert_price(price);
+        }
+    }
+
+    /// 版が、指定した版と一致するか確認する。
+    ///
+    /// # 引数
+    ///
+    /// * `version` - 版（`None`の場合は版を確認しない）
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Conflict` - 版が一致しない場合
+    fn ensure_version(&self, version: Option<u32>) -> DomainResult<()> {
+        match version {
+            Some(version) if version != self.version => Err(version_conflict_error()),
+            _ => Ok(()),
+        }
+    }
+
+    /// 野菜を更新した日時を記録して、版を1増やす。
+    ///
+    /// # 引数
+    ///
+    /// * `now` - 野菜を更新した日時
+    fn touch(&mut self, now: OffsetDateTime) {
+        self.version += 1;
+        self.updated_at = now;
+    }
+
+    /// 指定した日時の単価を持つ野菜を構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `at` - 日時
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜
+    fn to_vegetable(&self, at: OffsetDateTime) -> Vegetable {
+        Vegetable::new(
+            self.id.into(),
+            self.name.clone(),
+            self.unit_price(at),
+            self.tax_rate,
+            self.unit,
+            self.category_id,
+            self.tags.clone(),
+            self.available_months,
+            self.active,
+            self.version,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
+
+/// インメモリの野菜リポジトリ
+///
+/// 野菜をメモリに記録するため、データベースを使用せずにテストやデモで使用できる。
+/// クローンしたリポジトリは、同じ野菜を共有する。
+/// カテゴリは記録しないため、野菜を分類するカテゴリが存在するかは確認しない。
+/// 販売は記録しないため、テストでは販売明細が野菜を参照していることを`reference_by_sale`
+/// （`test-support`フィーチャー）で記録する。
+/// 販売明細が参照している野菜は、PostgreSQL用の野菜リポジトリと同様に物理削除できない。
+/// 監査ログは記録しないため、野菜を変更した利用者は無視する。
+/// アウトボックスを持たないため、野菜が発行したドメインイベントは記録しない。
+#[derive(Clone, Debug, Default)]
+pub struct MemoryVegetableRepository {
+    /// 野菜IDの順に並べた野菜
+    records: Arc<RwLock<BTreeMap<Uuid, VegetableRecord>>>,
+}
+
+impl MemoryVegetableRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 野菜を読み込むためにロックを取得する。
+    fn read(&self) -> DomainResult<RwLockReadGuard<'_, BTreeMap<Uuid, VegetableRecord>>> {
+        self.records
+            .read()
+            .map_err(|e| DomainError::Unexpected(anyhow::anyhow!(e.to_string())))
+    }
+
+    /// 野菜を書き込むためにロックを取得する。
+    fn write(&self) -> DomainResult<RwLockWriteGuard<'_, BTreeMap<Uuid, VegetableRecord>>> {
+        self.records
+            .write()
+            .map_err(|e| DomainError::Unexpected(anyhow::anyhow!(e.to_string())))
+    }
+
+    /// 販売明細が野菜を参照していることを記録する。
+    ///
+    /// インメモリの野菜リポジトリは販売を記録しないため、販売を登録する代わりに呼び出して、
+    /// 販売明細が参照している野菜を物理削除できないことを再現する。テストでのみ使用するため、
+    /// `test-support`フィーチャーを有効にした場合に限り使用できる。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売明細が参照している野菜の野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜が存在した場合は`true`（論理削除した野菜を含む）
+    #[cfg(feature = "test-support")]
+    pub fn reference_by_sale(&self, id: VegetableId) -> DomainResult<bool> {
+        Ok(match self.write()?.get_mut(&id.value()) {
+            Some(record) => {
+                record.referenced_by_sales = true;
+                true
+            }
+            None => false,
+        })
+    }
+}
+
+/// 野菜IDで指定した、論理削除していない野菜を取得する。
+///
+/// # 引数
+///
+/// * `records` - 野菜
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// 野菜（野菜が存在しないか、論理削除した場合は`None`）
+fn get_mut(
+    records: &mut BTreeMap<Uuid, VegetableRecord>,
+    id: VegetableId,
+) -> Option<&mut VegetableRecord> {
+    records
+        .get_mut(&id.value())
+        .filter(|r| r.deleted_at.is_none())
+}
+
+#[async_trait]
+impl VegetableRepository for MemoryVegetableRepository {
+    /// 野菜IDで指定した野菜を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜
+    async fn find_by_id(&self, id: VegetableId) -> DomainResult<Option<Vegetable>> {
+        let now = OffsetDateTime::now_utc();
+
+        Ok(self
+            .read()?
+            .get(&id.value())
+            .filter(|r| r.deleted_at.is_none())
+            .map(|r| r.to_vegetable(now)))
+    }
+
+    /// 野菜IDで指定した野菜を検索する。
+    ///
+    /// インメモリの野菜リポジトリはトランザクションを持たないため、野菜をロックしない。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜
+    async fn find_by_id_for_share(&self, id: VegetableId) -> DomainResult<Option<Vegetable>> {
+        self.find_by_id(id).await
+    }
+
+    /// 検索条件に一致する野菜を検索する。
+    ///
+    /// 並び替える項目が同じ野菜は、野菜IDの順に並べる。
+    ///
+    /// # 引数
+    ///
+    /// * `query` - 野菜の検索条件
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の検索結果
+    async fn find_all(&self, query: VegetableQuery) -> DomainResult<VegetablePage> {
+        let now = OffsetDateTime::now_utc();
+        let name = query.name.as_ref().map(|name| name.to_lowercase());
+        let records = self.read()?;
+        let mut matched: Vec<Vegetable> = records
+            .values()
+            .filter(|r| r.deleted_at.is_none())
+            .map(|r| r.to_vegetable(now))
+            .filter(|v| {
+                name.as_ref()
+                    .is_none_or(|name| v.name().value().to_lowercase().contains(name))
+                    && query.min_unit_price.is_none_or(|min| min <= v.unit_price())
+                    && query.max_unit_price.is_none_or(|max| v.unit_price() <= max)
+                    && query
+                        .category_id
+                        .is_none_or(|id| v.category_id() == Some(id))
+                    && query.tag.as_ref().is_none_or(|tag| v.tags().contains(tag))
+                    && query
+                        .available_in
+                        .is_none_or(|month| v.active() && v.available_months().contains(month))
+            })
+            .collect();
+        // 安定ソートのため、野菜IDの順は維持される
+        matched.sort_by(|a, b| {
+            let ordering = match query.sort_key {
+                VegetableSortKey::Name => a.name().cmp(b.name()),
+                VegetableSortKey::UnitPrice => a.unit_price().cmp(&b.unit_price()),
+                VegetableSortKey::CreatedAt => a.created_at().cmp(&b.created_at()),
+            };
+            match query.sort_order {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+        let total = matched.len() as u64;
+        let items = matched
+            .into_iter()
+            .skip(query.offset() as usize)
+            .take(query.limit as usize)
+            .collect();
+
+        Ok(VegetablePage::new(&query, items, total))
+    }
+
+    /// 野菜を登録する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable` - 登録する野菜
+    /// * `_actor` - 野菜を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した野菜
+    async fn register(
+        &self,
+        vegetable: UpsertVegetable,
+        _actor: &Actor,
+    ) -> DomainResult<Vegetable> {
+        let now = OffsetDateTime::now_utc();
+        let mut records = self.write()?;
+        ensure_unique_name(&records, None, &vegetable.name)?;
+        let record = VegetableRecord {
+            id: Uuid::new_v4(),
+            name: vegetable.name,
+            prices: vec![VegetablePrice::new(vegetable.unit_price, now)],
+            tax_rate: vegetable.tax_rate,
+            unit: vegetable.unit,
+            category_id: vegetable.category_id,
+            tags: vegetable.tags,
+            available_months: vegetable.available_months,
+            active: vegetable.active,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            referenced_by_sales: false,
+        };
+        let veg = record.to_vegetable(now);
+        records.insert(record.id, record);
+
+        Ok(veg)
+    }
+
+    /// 野菜を更新する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 更新する野菜の野菜ID
+    /// * `version` - 更新する野菜の版（`None`の場合は版を確認しない）
+    /// * `vegetable` - 更新する野菜
+    /// * `_actor` - 野菜を更新する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 更新した野菜
+    async fn update(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        vegetable: UpsertVegetable,
+        _actor: &Actor,
+    ) -> DomainResult<Option<Vegetable>> {
+        let mut records = self.write()?;
+        ensure_unique_name(&records, Some(id.value()), &vegetable.name)?;
+        let record = match get_mut(&mut records, id) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        record.ensure_version(version)?;
+        let now = OffsetDateTime::now_utc();
+        record.name = vegetable.name;
+        record.change_unit_price(vegetable.unit_price, now);
+        record.tax_rate = vegetable.tax_rate;
+        record.unit = vegetable.unit;
+        record.category_id = vegetable.category_id;
+        record.tags = vegetable.tags;
+        record.available_months = vegetable.available_months;
+        record.active = vegetable.active;
+        record.touch(now);
+
+        Ok(Some(record.to_vegetable(now)))
+    }
+
+    /// 野菜を部分更新する。
+    ///
+    /// 野菜名、単価、消費税率、販売する単位、カテゴリ、タグ、販売する月及び販売中かどうかの
+    /// いずれも指定されていない場合は、野菜を更新しない。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 部分更新する野菜の野菜ID
+    /// * `version` - 部分更新する野菜の版（`None`の場合は版を確認しない）
+    /// * `vegetable` - 部分更新する野菜
+    /// * `_actor` - 野菜を部分更新する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 部分更新した野菜
+    async fn partial_update(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        vegetable: PartialVegetable,
+        _actor: &Actor,
+    ) -> DomainResult<Option<Vegetable>> {
+        if vegetable.name.is_none()
+            && vegetable.unit_price.is_none()
+            && vegetable.tax_rate.is_none()
+            && vegetable.unit.is_none()
+            && vegetable.category_id.is_none()
+            && vegetable.tags.is_none()
+            && vegetable.available_months.is_none()
+            && vegetable.active.is_none()
+        {
+            let veg = self.find_by_id(id).await?;
+            if let Some(veg) = &veg {
+                veg.ensure_version(version)?;
+            }
+            return Ok(veg);
+        }
+        let mut records = self.write()?;
+        if let Some(name) = &vegetable.name {
+            ensure_unique_name(&records, Some(id.value()), name)?;
+        }
+        let record = match get_mut(&mut records, id) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        record.ensure_version(version)?;
+        let now = OffsetDateTime::now_utc();
+        if let Some(name) = vegetable.name {
+            record.name = name;
+        }
+        if let Some(unit_price) = vegetable.unit_price {
+            record.change_unit_price(unit_price, now);
+        }
+        if let Some(tax_rate) = vegetable.tax_rate {
+            record.tax_rate = tax_rate;
+        }
+        if let Some(unit) = vegetable.unit {
+            record.unit = unit;
+        }
+        if let Some(category_id) = vegetable.category_id {
+            record.category_id = category_id;
+        }
+        if let Some(tags) = vegetable.tags {
+            record.tags = tags;
+        }
+        if let Some(available_months) = vegetable.available_months {
+            record.available_months = available_months;
+        }
+        if let Some(active) = vegetable.active {
+            record.active = active;
+        }
+        record.touch(now);
+
+        Ok(Some(record.to_vegetable(now)))
+    }
+
+    /// 野菜IDで指定した野菜の価格の履歴を、適用開始日時の順に検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の価格のベクタ
+    async fn find_prices(&self, id: VegetableId) -> DomainResult<Option<Vec<VegetablePrice>>> {
+        Ok(self
+            .read()?
+            .get(&id.value())
+            .filter(|r| r.deleted_at.is_none())
+            .map(|r| r.prices.clone()))
+    }
+
+    /// 野菜IDで指定した野菜の価格を登録する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `price` - 登録する野菜の価格
+    /// * `_actor` - 野菜の価格を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した野菜の価格
+    async fn register_price(
+        &self,
+        id: VegetableId,
+        price: VegetablePrice,
+        _actor: &Actor,
+    ) -> DomainResult<Option<VegetablePrice>> {
+        let mut records = self.write()?;
+        let record = match get_mut(&mut records, id) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        // 現在の単価と同じ単価を現在から適用する場合は、価格を記録せず、版も増やさない
+        let now = OffsetDateTime::now_utc();
+        if record.to_vegetable(now).change_price(price) {
+            record.upsert_price(price);
+            record.touch(now);
+        }
+
+        Ok(Some(price))
+    }
+
+    /// 野菜IDで指定した野菜を論理削除する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `version` - 論理削除する野菜の版（`None`の場合は版を確認しない）
+    /// * `_actor` - 野菜を論理削除する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 影響した行数。
+    async fn delete(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        _actor: &Actor,
+    ) -> DomainResult<u32> {
+        let mut records = self.write()?;
+        match get_mut(&mut records, id) {
+            Some(record) => {
+                record.ensure_version(version)?;
+                let now = OffsetDateTime::now_utc();
+                record.deleted_at = Some(now);
+                record.touch(now);
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// 野菜IDで指定した、論理削除した野菜を復元する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `_actor` - 野菜を復元する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 復元した野菜
+    async fn restore(&self, id: VegetableId, _actor: &Actor) -> DomainResult<Option<Vegetable>> {
+        let mut records = self.write()?;
+        let name = match records.get(&id.value()) {
+            Some(record) if record.deleted_at.is_some() => record.name.clone(),
+            Some(record) => return Ok(Some(record.to_vegetable(OffsetDateTime::now_utc()))),
+            None => return Ok(None),
+        };
+        ensure_unique_name(&records, Some(id.value()), &name)?;
+        let now = OffsetDateTime::now_utc();
+        // 直前に野菜が存在することを確認しているため、野菜は必ず存在する
+        let record = records.get_mut(&id.value()).unwrap();
+        record.deleted_at = None;
+        record.touch(now);
+
+        Ok(Some(record.to_vegetable(now)))
+    }
+
+    /// 野菜IDで指定した野菜を物理削除する。
+    ///
+    /// 論理削除した野菜も物理削除する。`reference_by_sale`で販売明細が参照していることを
+    /// 記録した野菜は、`DomainError::DomainRule`を返す。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `version` - 物理削除する野菜の版（`None`の場合は版を確認しない）
+    /// * `_actor` - 野菜を物理削除する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 影響した行数。
+    async fn purge(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        _actor: &Actor,
+    ) -> DomainResult<u32> {
+        let mut records = self.write()?;
+        match records.get(&id.value()) {
+            Some(record) => {
+                record.ensure_version(version)?;
+                if record.referenced_by_sales {
+                    return Err(DomainError::DomainRule(
+                        "販売で参照されている野菜は物理削除できません。".into(),
+                    ));
+                }
+            }
+            None => return Ok(0),
+        }
+        records.remove(&id.value());
+
+        Ok(1)
+    }
+}
+
+/// 野菜名が、論理削除していない他の野菜と重複していないか確認する。
+///
+/// # 引数
+///
+/// * `records` - 野菜
+/// * `id` - 野菜名を変更する野菜の野菜ID（登録する場合は`None`）
+/// * `name` - 野菜名
+///
+/// # エラー
+///
+/// * `DomainError::DomainRule` - 同じ野菜名の他の野菜が既に登録されている場合
+fn ensure_unique_name(
+    records: &BTreeMap<Uuid, VegetableRecord>,
+    id: Option<Uuid>,
+    name: &VegetableName,
+) -> DomainResult<()> {
+    if records
+        .values()
+        .any(|r| Some(r.id) != id && r.deleted_at.is_none() && &r.name == name)
+    {
+        return Err(DomainError::DomainRule(
+            "同じ名前の野菜が既に登録されています。".into(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+use crate::postgres::repositories::audit::PgAuditLogRepository;
+use usecase::interactors::audit::AuditLogInteractorImpl;
+
+/// PostgreSQL用の監査ログインタラクター
+pub type PgAuditLogInteractor = AuditLogInteractorImpl<PgAuditLogRepository>;
@@ -0,0 +1,5 @@
+use crate::postgres::repositories::category::PgCategoryRepository;
+use usecase::interactors::category::CategoryInteractorImpl;
+
+/// PostgreSQL用のカテゴリインタラクター
+pub type PgCategoryInteractor = CategoryInteractorImpl<PgCategoryRepository>;
@@ -0,0 +1,5 @@
+use crate::postgres::repositories::inventory::PgInventoryRepository;
+use usecase::interactors::inventory::InventoryInteractorImpl;
+
+/// PostgreSQL用の在庫インタラクター
+pub type PgInventoryInteractor = InventoryInteractorImpl<PgInventoryRepository>;
@@ -1 +1,6 @@
+pub mod audit;
+pub mod category;
+pub mod inventory;
+pub mod report;
+pub mod sale;
 pub mod vegetable;
@@ -0,0 +1,5 @@
+use crate::postgres::repositories::report::PgReportRepository;
+use usecase::interactors::report::ReportInteractorImpl;
+
+/// PostgreSQL用の売上集計インタラクター
+pub type PgReportInteractor = ReportInteractorImpl<PgReportRepository>;
@@ -0,0 +1,5 @@
+use crate::postgres::repositories::unit_of_work::PgUnitOfWorkFactory;
+use usecase::interactors::sale::SaleInteractorImpl;
+
+/// PostgreSQL用の販売インタラクター
+pub type PgSaleInteractor = SaleInteractorImpl<PgUnitOfWorkFactory>;
@@ -1,7 +1,18 @@
 pub mod interactors;
 pub mod repositories;
 
-use domain::models::vegetable::Vegetable;
+use domain::models::audit::AuditLog;
+use domain::models::availability::AvailableMonths;
+use domain::models::category::Category;
+use domain::models::events::DomainEvent;
+use domain::models::inventory::Inventory;
+use domain::models::primitives::{Tag, VegetableName, Weight};
+use domain::models::sales::{Sale, SaleDetail};
+use domain::models::tax::{TaxAmount, TaxRate};
+use domain::models::unit::UnitOfMeasure;
+use domain::models::vegetable::{Vegetable, VegetablePrice};
+use domain::repositories::vegetable::VegetablePage;
+use domain::{DomainError, DomainResult};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -11,34 +22,435 @@ pub struct PlainVegetable {
     id: Uuid,
     name: String,
     unit_price: i32,
+    tax_rate: String,
+    unit: String,
+    unit_grams: Option<i32>,
+    category_id: Option<Uuid>,
+    tags: Vec<String>,
+    available_months: Vec<i16>,
+    active: bool,
+    version: i32,
     #[serde(with = "time::serde::rfc3339")]
     created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
     updated_at: OffsetDateTime,
 }
 
-impl From<PlainVegetable> for Vegetable {
-    fn from(value: PlainVegetable) -> Self {
-        // 永続化層からのデータはドメインルールを満たしていることを前提とするため、
-        // エラー処理を省略
-        Self::new(
-            value.id.into(),
-            &value.name,
-            value.unit_price.try_into().unwrap(),
+impl TryFrom<PlainVegetable> for Vegetable {
+    type Error = DomainError;
+
+    fn try_from(value: PlainVegetable) -> Result<Self, Self::Error> {
+        let id = value.id;
+        let name = stored_vegetable_name(id, value.name)?;
+        let invalid = |e| stored_data_error("野菜", id, e);
+        let unit_grams = value
+            .unit_grams
+            .map(Weight::try_from)
+            .transpose()
+            .map_err(invalid)?;
+        let tags = value
+            .tags
+            .into_iter()
+            .map(Tag::try_from)
+            .collect::<DomainResult<Vec<_>>>()
+            .map_err(invalid)?;
+
+        Ok(Self::new(
+            id.into(),
+            name,
+            value.unit_price.try_into().map_err(invalid)?,
+            TaxRate::try_from(value.tax_rate.as_str()).map_err(invalid)?,
+            UnitOfMeasure::from_code(value.unit.as_str(), unit_grams).map_err(invalid)?,
+            value.category_id.map(|id| id.into()),
+            tags,
+            AvailableMonths::try_from(value.available_months.as_slice()).map_err(invalid)?,
+            value.active,
+            u32::try_from(value.version).map_err(|e| stored_data_error("野菜", id, e))?,
             value.created_at,
             value.updated_at,
-        )
+        ))
     }
 }
 
+/// 永続化層から取得した野菜名を、野菜名に変換する。
+///
+/// 野菜名を値オブジェクトにする前に登録された野菜名は、野菜名のドメインルールを満たして
+/// いない可能性があるため、パニックせずにエラーを返す。
+///
+/// # 引数
+///
+/// * `id` - 野菜ID
+/// * `name` - 永続化層から取得した野菜名
+///
+/// # 戻り値
+///
+/// 野菜名
+///
+/// # エラー
+///
+/// * `DomainError::Unexpected` - 野菜名がドメインルールを満たしていない場合
+pub(crate) fn stored_vegetable_name(id: Uuid, name: String) -> DomainResult<VegetableName> {
+    VegetableName::try_from(name.as_str()).map_err(|e| {
+        DomainError::Unexpected(anyhow::anyhow!(
+            "登録されている野菜名が正しくありません。(野菜ID: {}, 野菜名: {:?}, 理由: {})",
+            id,
+            name,
+            e
+        ))
+    })
+}
+
+/// 永続化層から取得したデータがドメインルールを満たしていないことを表すエラーを構築する。
+///
+/// # 引数
+///
+/// * `entity` - データを取得したエンティティの名前
+/// * `id` - データを取得したエンティティのID
+/// * `error` - ドメインモデルに変換できなかった理由
+///
+/// # 戻り値
+///
+/// `DomainError::Unexpected`
+pub(crate) fn stored_data_error<E>(entity: &str, id: Uuid, error: E) -> DomainError
+where
+    E: std::fmt::Display,
+{
+    DomainError::Unexpected(anyhow::anyhow!(
+        "登録されている{}が正しくありません。({}ID: {}, 理由: {})",
+        entity,
+        entity,
+        id,
+        error
+    ))
+}
+
 impl From<Vegetable> for PlainVegetable {
     fn from(value: Vegetable) -> Self {
         Self {
             id: value.id().value(),
-            name: value.name().to_string(),
-            unit_price: value.unit_price().value() as i32,
+            name: value.name().value().to_string(),
+            unit_price: value.unit_price().into(),
+            tax_rate: value.tax_rate().code().to_string(),
+            unit: value.unit().code().to_string(),
+            unit_grams: value.unit().grams().map(|g| g.into()),
+            category_id: value.category_id().map(|id| id.value()),
+            tags: value.tags().iter().map(|t| t.value().to_string()).collect(),
+            available_months: value
+                .available_months()
+                .months()
+                .into_iter()
+                .map(i16::from)
+                .collect(),
+            active: value.active(),
+            version: value.version() as i32,
+            created_at: value.created_at(),
+            updated_at: value.updated_at(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainVegetablePrice {
+    unit_price: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    effective_from: OffsetDateTime,
+}
+
+impl From<VegetablePrice> for PlainVegetablePrice {
+    fn from(value: VegetablePrice) -> Self {
+        Self {
+            unit_price: value.unit_price().value(),
+            effective_from: value.effective_from(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainVegetablePage {
+    items: Vec<PlainVegetable>,
+    total: u64,
+    next_page: Option<u32>,
+}
+
+impl From<VegetablePage> for PlainVegetablePage {
+    fn from(value: VegetablePage) -> Self {
+        Self {
+            items: value.items.into_iter().map(|v| v.into()).collect(),
+            total: value.total,
+            next_page: value.next_page,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainSale {
+    id: Uuid,
+    #[serde(with = "time::serde::rfc3339")]
+    sold_at: OffsetDateTime,
+    sale_details: Vec<PlainSaleDetail>,
+    tax_inclusion: String,
+    tax_rounding: String,
+    subtotal: u64,
+    taxes: Vec<PlainTaxAmount>,
+    tax_amount: u64,
+    total_price: u64,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+}
+
+impl From<Sale> for PlainSale {
+    fn from(value: Sale) -> Self {
+        Self {
+            id: value.id().value(),
+            sold_at: value.sold_at(),
+            tax_inclusion: value.tax_policy().inclusion.code().to_string(),
+            tax_rounding: value.tax_policy().rounding.code().to_string(),
+            subtotal: value.subtotal().value(),
+            taxes: value.taxes().iter().map(|t| t.into()).collect(),
+            tax_amount: value.tax_amount().value(),
+            total_price: value.total_price().value(),
+            created_at: value.created_at(),
+            updated_at: value.updated_at(),
+            sale_details: value.sale_details().iter().map(|sd| sd.into()).collect(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainSaleDetail {
+    id: Uuid,
+    vegetable_id: Uuid,
+    vegetable_name: String,
+    sold_unit_price: u32,
+    unit: String,
+    unit_grams: Option<u32>,
+    sold_quantity: u32,
+    sold_weight: Option<u32>,
+    item_price: u32,
+    tax_rate: String,
+    subtotal: u64,
+}
+
+impl From<&SaleDetail> for PlainSaleDetail {
+    fn from(value: &SaleDetail) -> Self {
+        Self {
+            id: value.id().value(),
+            vegetable_id: value.vegetable().id().value(),
+            vegetable_name: value.vegetable().name().value().to_string(),
+            sold_unit_price: value.sold_unit_price().value(),
+            unit: value.unit().code().to_string(),
+            unit_grams: value.unit().grams().map(|g| g.grams()),
+            sold_quantity: value.sold_quantity().value(),
+            sold_weight: value.sold_weight().map(|w| w.grams()),
+            item_price: value.item_price().value(),
+            tax_rate: value.tax_rate().code().to_string(),
+            subtotal: value.subtotal().value(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainTaxAmount {
+    rate: String,
+    taxable_amount: u64,
+    tax_amount: u64,
+}
+
+impl From<&TaxAmount> for PlainTaxAmount {
+    fn from(value: &TaxAmount) -> Self {
+        Self {
+            rate: value.rate().code().to_string(),
+            taxable_amount: value.taxable_amount().value(),
+            tax_amount: value.tax_amount().value(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainInventory {
+    vegetable_id: Uuid,
+    quantity: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+}
+
+impl From<Inventory> for PlainInventory {
+    fn from(value: Inventory) -> Self {
+        Self {
+            vegetable_id: value.vegetable_id().value(),
+            quantity: value.quantity(),
+            created_at: value.created_at(),
+            updated_at: value.updated_at(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainCategory {
+    id: Uuid,
+    name: String,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated_at: OffsetDateTime,
+}
+
+impl From<Category> for PlainCategory {
+    fn from(value: Category) -> Self {
+        Self {
+            id: value.id().value(),
+            name: value.name().value().to_string(),
             created_at: value.created_at(),
             updated_at: value.updated_at(),
         }
     }
 }
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlainAuditLog {
+    id: u64,
+    entity_type: String,
+    entity_id: Uuid,
+    operation: String,
+    before: Option<serde_json::Value>,
+    after: Option<serde_json::Value>,
+    actor: String,
+    #[serde(with = "time::serde::rfc3339")]
+    recorded_at: OffsetDateTime,
+}
+
+impl From<AuditLog> for PlainAuditLog {
+    fn from(value: AuditLog) -> Self {
+        // 永続化層からのデータはドメインルールを満たしていることを前提とするため、エラー処理を省略
+        Self {
+            id: value.id(),
+            entity_type: value.entity_type().code().to_string(),
+            entity_id: value.entity_id(),
+            operation: value.operation().code().to_string(),
+            before: value.before().map(|b| serde_json::from_str(b).unwrap()),
+            after: value.after().map(|a| serde_json::from_str(a).unwrap()),
+            actor: value.actor().value().to_string(),
+            recorded_at: value.recorded_at(),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PlainDomainEvent {
+    #[serde(rename_all = "camelCase")]
+    VegetablePriceChanged {
+        vegetable_id: Uuid,
+        previous_unit_price: u32,
+        unit_price: u32,
+        #[serde(with = "time::serde::rfc3339")]
+        effective_from: OffsetDateTime,
+    },
+    #[serde(rename_all = "camelCase")]
+    SaleCompleted {
+        sale_id: Uuid,
+        #[serde(with = "time::serde::rfc3339")]
+        sold_at: OffsetDateTime,
+        total_price: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemReturned {
+        sale_id: Uuid,
+        vegetable_id: Uuid,
+        quantity: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    SaleCancelled { sale_id: Uuid },
+}
+
+impl From<&DomainEvent> for PlainDomainEvent {
+    fn from(value: &DomainEvent) -> Self {
+        match value {
+            DomainEvent::VegetablePriceChanged {
+                vegetable_id,
+                previous_unit_price,
+                unit_price,
+                effective_from,
+            } => Self::VegetablePriceChanged {
+                vegetable_id: vegetable_id.value(),
+                previous_unit_price: previous_unit_price.value(),
+                unit_price: unit_price.value(),
+                effective_from: *effective_from,
+            },
+            DomainEvent::SaleCompleted {
+                sale_id,
+                sold_at,
+                total_price,
+            } => Self::SaleCompleted {
+                sale_id: sale_id.value(),
+                sold_at: *sold_at,
+                total_price: total_price.value(),
+            },
+            DomainEvent::ItemReturned {
+                sale_id,
+                vegetable_id,
+                quantity,
+            } => Self::ItemReturned {
+                sale_id: sale_id.value(),
+                vegetable_id: vegetable_id.value(),
+                quantity: quantity.value(),
+            },
+            DomainEvent::SaleCancelled { sale_id } => Self::SaleCancelled {
+                sale_id: sale_id.value(),
+            },
+        }
+    }
+}
+
+impl TryFrom<PlainDomainEvent> for DomainEvent {
+    type Error = DomainError;
+
+    fn try_from(value: PlainDomainEvent) -> Result<Self, Self::Error> {
+        Ok(match value {
+            PlainDomainEvent::VegetablePriceChanged {
+                vegetable_id,
+                previous_unit_price,
+                unit_price,
+                effective_from,
+            } => Self::VegetablePriceChanged {
+                vegetable_id: vegetable_id.into(),
+                previous_unit_price: previous_unit_price.try_into()?,
+                unit_price: unit_price.try_into()?,
+                effective_from,
+            },
+            PlainDomainEvent::SaleCompleted {
+                sale_id,
+                sold_at,
+                total_price,
+            } => Self::SaleCompleted {
+                sale_id: sale_id.into(),
+                sold_at,
+                total_price: total_price.into(),
+            },
+            PlainDomainEvent::ItemReturned {
+                sale_id,
+                vegetable_id,
+                quantity,
+            } => Self::ItemReturned {
+                sale_id: sale_id.into(),
+                vegetable_id: vegetable_id.into(),
+                quantity: quantity.try_into()?,
+            },
+            PlainDomainEvent::SaleCancelled { sale_id } => Self::SaleCancelled {
+                sale_id: sale_id.into(),
+            },
+        })
+    }
+}
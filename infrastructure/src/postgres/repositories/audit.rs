@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use sqlx::postgres::{PgConnection, PgPool};
+use sqlx::{Postgres, QueryBuilder};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{acquire_connection, PgExecutor};
+use domain::models::audit::{AuditEntityType, AuditLog, AuditOperation};
+use domain::models::primitives::Actor;
+use domain::repositories::audit::{AuditLogQuery, AuditLogRepository};
+use domain::{DomainError, DomainResult};
+
+/// 監査ログレコード
+#[derive(sqlx::FromRow)]
+struct AuditLogRecord {
+    id: i64,
+    entity_type: String,
+    entity_id: Uuid,
+    operation: String,
+    before: Option<String>,
+    after: Option<String>,
+    actor: String,
+    recorded_at: OffsetDateTime,
+}
+
+impl From<AuditLogRecord> for AuditLog {
+    fn from(value: AuditLogRecord) -> Self {
+        // 永続化層からのデータはドメインルールを満たしていることを前提とするため、
+        // エラー処理を省略
+        Self::new(
+            value.id.try_into().unwrap(),
+            AuditEntityType::try_from(value.entity_type.as_str()).unwrap(),
+            value.entity_id,
+            AuditOperation::try_from(value.operation.as_str()).unwrap(),
+            value.before,
+            value.after,
+            value.actor.try_into().unwrap(),
+            value.recorded_at,
+        )
+    }
+}
+
+/// PostgreSQL用の監査ログリポジトリ
+#[derive(Clone, Debug)]
+pub struct PgAuditLogRepository {
+    executor: PgExecutor,
+}
+
+impl PgAuditLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for PgAuditLogRepository {
+    /// 検索条件に一致する監査ログを、記録した日時の新しい順に検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `query` - 監査ログの検索条件
+    ///
+    /// # 戻り値
+    ///
+    /// 監査ログのベクタ
+    async fn find_all(&self, query: AuditLogQuery) -> DomainResult<Vec<AuditLog>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT \
+                id, entity_type, entity_id, operation, before::TEXT, after::TEXT, actor, \
+                recorded_at \
+             FROM audit_logs \
+             WHERE TRUE",
+        );
+        if let Some(entity_type) = query.entity_type {
+            builder.push(" AND entity_type = ");
+            builder.push_bind(entity_type.code());
+        }
+        if let Some(entity_id) = query.entity_id {
+            builder.push(" AND entity_id = ");
+            builder.push_bind(entity_id);
+        }
+        builder.push(" ORDER BY id DESC LIMIT ");
+        builder.push_bind(query.limit as i64);
+        let records = builder
+            .build_query_as::<AuditLogRecord>()
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(records.into_iter().map(|r| r.into()).collect())
+    }
+}
+
+/// 監査ログに記録する変更
+pub(crate) struct AuditLogEntry<'a> {
+    /// 変更したエンティティの種類
+    pub entity_type: AuditEntityType,
+    /// 変更したエンティティのID
+    pub entity_id: Uuid,
+    /// 操作
+    pub operation: AuditOperation,
+    /// 変更前の状態を表すJSON
+    pub before: Option<String>,
+    /// 変更後の状態を表すJSON
+    pub after: Option<String>,
+    /// 変更した利用者
+    pub actor: &'a Actor,
+}
+
+/// 監査ログを記録する。
+///
+/// 変更と同一のトランザクションで記録するため、変更に使用しているデータベース接続を
+/// 受け取る。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `entry` - 監査ログに記録する変更
+///
+/// # 戻り値
+///
+/// `()`
+pub(crate) async fn insert_audit_log(
+    conn: &mut PgConnection,
+    entry: AuditLogEntry<'_>,
+) -> DomainResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO audit_logs (
+            entity_type, entity_id, operation, before, after, actor, recorded_at
+        )
+        VALUES ($1, $2, $3, $4::TEXT::JSONB, $5::TEXT::JSONB, $6, CURRENT_TIMESTAMP)
+        "#,
+        entry.entity_type.code(),
+        entry.entity_id,
+        entry.operation.code(),
+        entry.before,
+        entry.after,
+        entry.actor.value(),
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(())
+}
+
+/// 監査ログに記録する状態を、JSONに変換する。
+///
+/// # 引数
+///
+/// * `value` - 監査ログに記録する状態
+///
+/// # 戻り値
+///
+/// 状態を表すJSON
+pub(crate) fn to_snapshot<T>(value: &T) -> DomainResult<String>
+where
+    T: serde::Serialize,
+{
+    serde_json::to_string(value).map_err(|e| DomainError::Unexpected(e.into()))
+}
@@ -0,0 +1,210 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{acquire_connection, begin_transaction, commit_transaction, PgExecutor};
+use domain::models::category::{Category, CategoryId};
+use domain::repositories::category::{CategoryRepository, UpsertCategory};
+use domain::{DomainError, DomainResult};
+
+/// カテゴリレコード
+struct CategoryRecord {
+    id: Uuid,
+    name: String,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+impl From<CategoryRecord> for Category {
+    fn from(value: CategoryRecord) -> Self {
+        // 永続化層からのデータはドメインルールを満たしていることを前提とするため、
+        // エラー処理を省略
+        Self::new(
+            value.id.into(),
+            value.name.try_into().unwrap(),
+            value.created_at,
+            value.updated_at,
+        )
+    }
+}
+
+/// PostgreSQL用のカテゴリリポジトリ
+#[derive(Clone, Debug)]
+pub struct PgCategoryRepository {
+    executor: PgExecutor,
+}
+
+impl PgCategoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for PgCategoryRepository {
+    /// カテゴリIDで指定したカテゴリを検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - カテゴリID
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ
+    async fn find_by_id(&self, id: CategoryId) -> DomainResult<Option<Category>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let record = sqlx::query_as!(
+            CategoryRecord,
+            r#"
+            SELECT id, name, created_at, updated_at
+            FROM categories
+            WHERE id = $1
+            "#,
+            id.value(),
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(record.map(|r| r.into()))
+    }
+
+    /// すべてのカテゴリを、カテゴリ名の順に検索する。
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリのベクタ
+    async fn find_all(&self) -> DomainResult<Vec<Category>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let records = sqlx::query_as!(
+            CategoryRecord,
+            r#"
+            SELECT id, name, created_at, updated_at
+            FROM categories
+            ORDER BY name, id
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(records.into_iter().map(|r| r.into()).collect())
+    }
+
+    /// カテゴリを登録する。
+    ///
+    /// # 引数
+    ///
+    /// * `category` - 登録するカテゴリ
+    ///
+    /// # 戻り値
+    ///
+    /// 登録したカテゴリ
+    async fn register(&self, category: UpsertCategory) -> DomainResult<Category> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let record = sqlx::query_as!(
+            CategoryRecord,
+            r#"
+            INSERT INTO categories (id, name, created_at, updated_at)
+            VALUES ($1, $2, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+            RETURNING id, name, created_at, updated_at
+            "#,
+            Uuid::new_v4(),
+            category.name.value(),
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(convert_write_error)?;
+        commit_transaction(tx).await?;
+
+        Ok(record.into())
+    }
+
+    /// カテゴリを更新する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 更新するカテゴリのカテゴリID
+    /// * `category` - 更新するカテゴリ
+    ///
+    /// # 戻り値
+    ///
+    /// 更新したカテゴリ
+    async fn update(
+        &self,
+        id: CategoryId,
+        category: UpsertCategory,
+    ) -> DomainResult<Option<Category>> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let record = sqlx::query_as!(
+            CategoryRecord,
+            r#"
+            UPDATE categories
+            SET name = $2, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING id, name, created_at, updated_at
+            "#,
+            id.value(),
+            category.name.value(),
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(convert_write_error)?;
+        commit_transaction(tx).await?;
+
+        Ok(record.map(|r| r.into()))
+    }
+
+    /// カテゴリIDで指定したカテゴリを削除する。
+    ///
+    /// 削除したカテゴリに分類されていた野菜は、外部キー制約によりカテゴリに分類されて
+    /// いない野菜になる。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - カテゴリID
+    ///
+    /// # 戻り値
+    ///
+    /// 影響した行数。
+    async fn delete(&self, id: CategoryId) -> DomainResult<u32> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let result = {
+            sqlx::query!(
+                r#"
+                DELETE FROM categories
+                WHERE id = $1
+                "#,
+                id.value(),
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?
+        };
+        commit_transaction(tx).await?;
+
+        Ok(result.rows_affected() as u32)
+    }
+}
+
+/// カテゴリを登録または更新した時のエラーを、ドメインエラーに変換する。
+///
+/// # 引数
+///
+/// * `e` - カテゴリを登録または更新した時のエラー
+///
+/// # 戻り値
+///
+/// カテゴリ名の一意制約に違反した場合は`DomainError::DomainRule`、それ以外の場合は
+/// `DomainError::Unexpected`
+fn convert_write_error(e: sqlx::Error) -> DomainError {
+    match e.as_database_error() {
+        Some(db_error) if db_error.is_unique_violation() => {
+            DomainError::DomainRule("同じ名前のカテゴリが既に登録されています。".into())
+        }
+        _ => DomainError::Unexpected(e.into()),
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use sqlx::postgres::{PgConnection, PgPool};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{
+    acquire_connection, begin_transaction, commit_transaction, PgExecutor, SharedTransaction,
+};
+use domain::models::inventory::Inventory;
+use domain::models::primitives::Quantity;
+use domain::models::vegetable::VegetableId;
+use domain::repositories::inventory::InventoryRepository;
+use domain::{DomainError, DomainResult};
+
+/// 在庫レコード
+struct InventoryRecord {
+    vegetable_id: Uuid,
+    quantity: i32,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+impl From<InventoryRecord> for Inventory {
+    fn from(value: InventoryRecord) -> Self {
+        // 永続化層からのデータはドメインルールを満たしていることを前提とするため、
+        // エラー処理を省略
+        Self::reconstruct(
+            value.vegetable_id.into(),
+            value.quantity.try_into().unwrap(),
+            value.created_at,
+            value.updated_at,
+        )
+    }
+}
+
+/// PostgreSQL用の在庫リポジトリ
+#[derive(Clone, Debug)]
+pub struct PgInventoryRepository {
+    executor: PgExecutor,
+}
+
+impl PgInventoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+
+    /// ユニットオブワークのトランザクションに参加するリポジトリを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `tx` - ユニットオブワークが共有するトランザクション
+    ///
+    /// # 戻り値
+    ///
+    /// リポジトリ
+    pub(crate) fn in_unit_of_work(tx: SharedTransaction) -> Self {
+        Self {
+            executor: PgExecutor::UnitOfWork(tx),
+        }
+    }
+}
+
+#[async_trait]
+impl InventoryRepository for PgInventoryRepository {
+    /// 野菜IDで指定した野菜の在庫を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫
+    async fn find_by_vegetable_id(
+        &self,
+        vegetable_id: VegetableId,
+    ) -> DomainResult<Option<Inventory>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let record = sqlx::query_as!(
+            InventoryRecord,
+            r#"
+            SELECT vegetable_id, quantity, created_at, updated_at
+            FROM inventories
+            WHERE vegetable_id = $1
+            "#,
+            vegetable_id.value(),
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(record.map(|r| r.into()))
+    }
+
+    /// 在庫を管理しているすべての野菜の在庫を検索する。
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫のベクタ
+    async fn find_all(&self) -> DomainResult<Vec<Inventory>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let records = sqlx::query_as!(
+            InventoryRecord,
+            r#"
+            SELECT vegetable_id, quantity, created_at, updated_at
+            FROM inventories
+            ORDER BY vegetable_id
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(records.into_iter().map(|r| r.into()).collect())
+    }
+
+    /// 野菜を入荷する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    /// * `quantity` - 入荷した数量
+    ///
+    /// # 戻り値
+    ///
+    /// 入荷した数量を加えた在庫
+    async fn receive(
+        &self,
+        vegetable_id: VegetableId,
+        quantity: Quantity,
+    ) -> DomainResult<Option<Inventory>> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        // 在庫を管理していない野菜の場合は、在庫数が0の在庫を登録してから入荷する
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO inventories (vegetable_id, quantity, created_at, updated_at)
+            VALUES ($1, 0, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+            ON CONFLICT (vegetable_id) DO NOTHING
+            "#,
+            vegetable_id.value(),
+        )
+        .execute(&mut *tx)
+        .await;
+        match result {
+            Ok(_) => {}
+            Err(e) => match e.as_database_error() {
+                Some(db_error) if db_error.is_foreign_key_violation() => return Ok(None),
+                _ => return Err(DomainError::Unexpected(e.into())),
+            },
+        }
+        let inventory = match lock_inventories(&mut tx, &[vegetable_id.value()])
+            .await?
+            .pop()
+        {
+            Some(inventory) => inventory.receive(quantity)?,
+            None => return Ok(None),
+        };
+        update_inventory(&mut tx, &inventory).await?;
+        commit_transaction(tx).await?;
+
+        Ok(Some(inventory))
+    }
+
+    /// 傷んだ野菜などを廃棄する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    /// * `quantity` - 廃棄する数量
+    ///
+    /// # 戻り値
+    ///
+    /// 廃棄した数量を減じた在庫
+    async fn dispose(
+        &self,
+        vegetable_id: VegetableId,
+        quantity: Quantity,
+    ) -> DomainResult<Option<Inventory>> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let inventory = match lock_inventories(&mut tx, &[vegetable_id.value()])
+            .await?
+            .pop()
+        {
+            Some(inventory) => inventory.dispose(quantity)?,
+            None => return Ok(None),
+        };
+        update_inventory(&mut tx, &inventory).await?;
+        commit_transaction(tx).await?;
+
+        Ok(Some(inventory))
+    }
+
+    /// 野菜IDで指定した野菜の在庫を削除する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 影響した行数。
+    async fn delete(&self, vegetable_id: VegetableId) -> DomainResult<u32> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let result = {
+            sqlx::query!(
+                r#"
+                DELETE FROM inventories
+                WHERE vegetable_id = $1
+                "#,
+                vegetable_id.value(),
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?
+        };
+        commit_transaction(tx).await?;
+
+        Ok(result.rows_affected() as u32)
+    }
+}
+
+/// 野菜ごとの在庫を増減する。
+///
+/// 在庫を管理していない野菜は、在庫が無限にあるものとして扱い、在庫を増減しない。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `changes` - 野菜IDをキー、在庫の増減（減らす場合は負の数）を値とした順序付きマップ
+///
+/// # 戻り値
+///
+/// `()`
+///
+/// # エラー
+///
+/// * `DomainError::DomainRule` - 在庫が不足する場合
+pub(super) async fn change_stocks(
+    conn: &mut PgConnection,
+    changes: &BTreeMap<Uuid, i64>,
+) -> DomainResult<()> {
+    let ids: Vec<Uuid> = changes
+        .iter()
+        .filter(|(_, change)| **change != 0)
+        .map(|(id, _)| *id)
+        .collect();
+    if ids.is_empty() {
+        return Ok(());
+    }
+    for inventory in lock_inventories(conn, &ids).await? {
+        let change = changes[&inventory.vegetable_id().value()];
+        let inventory = if change < 0 {
+            inventory.allocate(Quantity::try_from(-change)?)?
+        } else {
+            inventory.restore(Quantity::try_from(change)?)?
+        };
+        update_inventory(conn, &inventory).await?;
+    }
+
+    Ok(())
+}
+
+/// 野菜IDで指定した野菜の在庫を、更新するためにロックして取得する。
+///
+/// デッドロックを避けるため、野菜IDの順にロックする。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `vegetable_ids` - 野菜IDのスライス
+///
+/// # 戻り値
+///
+/// 野菜IDの順に並べた在庫のベクタ（在庫を管理していない野菜の在庫は含まない）
+async fn lock_inventories(
+    conn: &mut PgConnection,
+    vegetable_ids: &[Uuid],
+) -> DomainResult<Vec<Inventory>> {
+    let records = sqlx::query_as!(
+        InventoryRecord,
+        r#"
+        SELECT vegetable_id, quantity, created_at, updated_at
+        FROM inventories
+        WHERE vegetable_id = ANY($1)
+        ORDER BY vegetable_id
+        FOR UPDATE
+        "#,
+        vegetable_ids,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(records.into_iter().map(|r| r.into()).collect())
+}
+
+/// 在庫を更新する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `inventory` - 更新する在庫
+///
+/// # 戻り値
+///
+/// `()`
+async fn update_inventory(conn: &mut PgConnection, inventory: &Inventory) -> DomainResult<()> {
+    sqlx::query!(
+        r#"
+        UPDATE inventories
+        SET quantity = $2, updated_at = $3
+        WHERE vegetable_id = $1
+        "#,
+        inventory.vegetable_id().value(),
+        inventory.quantity() as i32,
+        inventory.updated_at(),
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(())
+}
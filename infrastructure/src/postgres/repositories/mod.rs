@@ -1,25 +1,181 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
 use domain::{DomainError, DomainResult};
-use sqlx::{Pool, Postgres, Transaction};
+use sqlx::pool::PoolConnection;
+use sqlx::postgres::{PgConnection, PgPool};
+use sqlx::{Postgres, Transaction};
+use tokio::sync::{MappedMutexGuard, Mutex, MutexGuard};
 
+pub mod audit;
+pub mod category;
+pub mod inventory;
+pub mod outbox;
+pub mod report;
+pub mod sale;
+pub mod unit_of_work;
 pub mod vegetable;
 
+/// ユニットオブワークに参加するリポジトリが共有するトランザクション
+///
+/// ユニットオブワークがコミットまたはロールバックすると、トランザクションを取り出して
+/// `None`にする。
+pub(crate) type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+/// リポジトリがデータベースにアクセスする経路
+#[derive(Clone, Debug)]
+pub(crate) enum PgExecutor {
+    /// データベース接続プール
+    ///
+    /// リポジトリのメソッドごとに、データベース接続を取得またはトランザクションを開始する。
+    Pool(PgPool),
+
+    /// ユニットオブワークが共有するトランザクション
+    ///
+    /// リポジトリのメソッドは、ユニットオブワークのトランザクションで実行する。
+    /// トランザクションのコミットとロールバックは、ユニットオブワークが行う。
+    UnitOfWork(SharedTransaction),
+}
+
+/// リポジトリのメソッドが使用するデータベース接続
+pub(crate) enum PgConnectionGuard<'a> {
+    /// データベース接続プールから取得した接続
+    Connection(PoolConnection<Postgres>),
+    /// リポジトリのメソッドで開始したトランザクション
+    Transaction(Transaction<'static, Postgres>),
+    /// ユニットオブワークが共有するトランザクション
+    UnitOfWork(MappedMutexGuard<'a, Transaction<'static, Postgres>>),
+}
+
+impl Deref for PgConnectionGuard<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Connection(conn) => conn,
+            Self::Transaction(tx) => tx,
+            Self::UnitOfWork(tx) => tx,
+        }
+    }
+}
+
+impl DerefMut for PgConnectionGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Connection(conn) => conn,
+            Self::Transaction(tx) => tx,
+            Self::UnitOfWork(tx) => tx,
+        }
+    }
+}
+
+/// ユニットオブワークが共有するトランザクションをロックする。
+///
+/// # 引数
+///
+/// * `tx` - ユニットオブワークが共有するトランザクション
+///
+/// # 戻り値
+///
+/// ユニットオブワークのトランザクション
+///
+/// # エラー
+///
+/// * `DomainError::Unexpected` - ユニットオブワークがコミットまたはロールバックされている
+///   場合
+async fn lock_unit_of_work(tx: &SharedTransaction) -> DomainResult<PgConnectionGuard<'_>> {
+    MutexGuard::try_map(tx.lock().await, Option::as_mut)
+        .map(PgConnectionGuard::UnitOfWork)
+        .map_err(|_| {
+            DomainError::Unexpected(anyhow::anyhow!(
+                "ユニットオブワークのトランザクションは終了しています。"
+            ))
+        })
+}
+
+/// データベース接続を取得する。
+///
+/// ユニットオブワークに参加するリポジトリの場合は、ユニットオブワークのトランザクションを
+/// 返す。
+///
+/// # 引数
+///
+/// * `executor` - リポジトリがデータベースにアクセスする経路
+///
+/// # 戻り値
+///
+/// データベース接続
+pub(crate) async fn acquire_connection(
+    executor: &PgExecutor,
+) -> DomainResult<PgConnectionGuard<'_>> {
+    match executor {
+        PgExecutor::Pool(pool) => pool
+            .acquire()
+            .await
+            .map(PgConnectionGuard::Connection)
+            .map_err(|e| DomainError::Unexpected(e.into())),
+        PgExecutor::UnitOfWork(tx) => lock_unit_of_work(tx).await,
+    }
+}
+
+/// ユニットオブワークのトランザクションを取得する。
+///
+/// 行をロックするメソッドは、ロックをユニットオブワークのトランザクションが終了するまで
+/// 保持するため、このトランザクションで実行する。データベース接続プールから開始した
+/// トランザクションは、メソッドの終了と同時に終了して、ロックが解放されるため使用しない。
+///
+/// # 引数
+///
+/// * `executor` - リポジトリがデータベースにアクセスする経路
+///
+/// # 戻り値
+///
+/// ユニットオブワークのトランザクション
+///
+/// # エラー
+///
+/// * `DomainError::Unexpected` - ユニットオブワークに参加していないリポジトリの場合
+pub(crate) async fn unit_of_work_transaction(
+    executor: &PgExecutor,
+) -> DomainResult<PgConnectionGuard<'_>> {
+    match executor {
+        PgExecutor::Pool(_) => Err(DomainError::Unexpected(anyhow::anyhow!(
+            "行をロックする検索は、ユニットオブワークのリポジトリで実行してください。"
+        ))),
+        PgExecutor::UnitOfWork(tx) => lock_unit_of_work(tx).await,
+    }
+}
+
 /// ドランザクションを開始する。
 ///
+/// ユニットオブワークに参加するリポジトリの場合は、新たにトランザクションを開始せずに、
+/// ユニットオブワークのトランザクションを返す。
+///
 /// # 引数
 ///
-/// * `pool` - データベース接続プール
+/// * `executor` - リポジトリがデータベースにアクセスする経路
 ///
 /// # 戻り値
 ///
 /// トランザクション
-pub async fn begin_transaction(pool: &Pool<Postgres>) -> DomainResult<Transaction<'_, Postgres>> {
-    pool.begin()
-        .await
-        .map_err(|e| DomainError::Unexpected(e.into()))
+pub(crate) async fn begin_transaction(
+    executor: &PgExecutor,
+) -> DomainResult<PgConnectionGuard<'_>> {
+    match executor {
+        PgExecutor::Pool(pool) => pool
+            .begin()
+            .await
+            .map(PgConnectionGuard::Transaction)
+            .map_err(|e| DomainError::Unexpected(e.into())),
+        PgExecutor::UnitOfWork(tx) => lock_unit_of_work(tx).await,
+    }
 }
 
 /// トランザクションをコミットする。
 ///
+/// ユニットオブワークのトランザクションは、ユニットオブワークがコミットするため、
+/// ここではコミットしない。
+///
 /// # 引数
 ///
 /// * `tx` - トランザクション
@@ -27,8 +183,12 @@ pub async fn begin_transaction(pool: &Pool<Postgres>) -> DomainResult<Transactio
 /// # 戻り値
 ///
 /// `()`
-pub async fn commit_transaction(tx: Transaction<'_, Postgres>) -> DomainResult<()> {
-    tx.commit()
-        .await
-        .map_err(|e| DomainError::Unexpected(e.into()))
+pub(crate) async fn commit_transaction(tx: PgConnectionGuard<'_>) -> DomainResult<()> {
+    match tx {
+        PgConnectionGuard::Transaction(tx) => tx
+            .commit()
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into())),
+        PgConnectionGuard::Connection(_) | PgConnectionGuard::UnitOfWork(_) => Ok(()),
+    }
 }
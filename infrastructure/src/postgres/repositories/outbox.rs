@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use sqlx::postgres::{PgConnection, PgPool};
+use time::OffsetDateTime;
+
+use super::{acquire_connection, PgExecutor};
+use crate::postgres::PlainDomainEvent;
+use domain::models::events::{DomainEvent, OutboxEvent, PendingEvent};
+use domain::repositories::outbox::OutboxRepository;
+use domain::{DomainError, DomainResult};
+
+/// アウトボックスレコード
+struct OutboxRecord {
+    id: i64,
+    payload: String,
+    occurred_at: OffsetDateTime,
+    attempts: i32,
+}
+
+impl From<OutboxRecord> for PendingEvent {
+    fn from(value: OutboxRecord) -> Self {
+        // IDと配信を試みた回数は、データベースの制約により0以上であるため、エラー処理を省略
+        let id = value.id.try_into().unwrap();
+        // ペイロードは、記録した時と異なる形式のドメインイベントである可能性があるため、
+        // 変換できない場合はパニックせずに変換できなかったことを返す
+        let event = serde_json::from_str::<PlainDomainEvent>(&value.payload)
+            .map_err(|e| e.to_string())
+            .and_then(|event| DomainEvent::try_from(event).map_err(|e| e.to_string()));
+        match event {
+            Ok(event) => Self::Decoded(OutboxEvent::new(
+                id,
+                event,
+                value.occurred_at,
+                value.attempts.try_into().unwrap(),
+            )),
+            Err(reason) => Self::Undecodable { id, reason },
+        }
+    }
+}
+
+/// PostgreSQL用のアウトボックスリポジトリ
+#[derive(Clone, Debug)]
+pub struct PgOutboxRepository {
+    executor: PgExecutor,
+}
+
+impl PgOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PgOutboxRepository {
+    /// 配信していないドメインイベントを、記録した順に検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `limit` - 検索するドメインイベントの最大数
+    /// * `max_attempts` - 配信を試みる最大回数
+    ///
+    /// # 戻り値
+    ///
+    /// 配信していないドメインイベントのベクタ
+    async fn find_pending(&self, limit: u32, max_attempts: u32) -> DomainResult<Vec<PendingEvent>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let records = sqlx::query_as!(
+            OutboxRecord,
+            r#"
+            SELECT id, payload::TEXT AS "payload!", occurred_at, attempts
+            FROM outbox
+            WHERE processed_at IS NULL AND attempts < $1
+            ORDER BY id
+            LIMIT $2
+            "#,
+            max_attempts as i32,
+            limit as i64,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(records.into_iter().map(|r| r.into()).collect())
+    }
+
+    /// ドメインイベントを配信したことを記録する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - アウトボックスに記録されたドメインイベントのID
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    async fn mark_processed(&self, id: u64) -> DomainResult<()> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        sqlx::query!(
+            r#"
+            UPDATE outbox
+            SET processed_at = CURRENT_TIMESTAMP, attempts = attempts + 1, last_error = NULL
+            WHERE id = $1
+            "#,
+            id as i64,
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(())
+    }
+
+    /// ドメインイベントの配信に失敗したことを記録して、配信を試みた回数を1増やす。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - アウトボックスに記録されたドメインイベントのID
+    /// * `error` - 配信に失敗したときのエラー
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    async fn mark_failed(&self, id: u64, error: &str) -> DomainResult<()> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        sqlx::query!(
+            r#"
+            UPDATE outbox
+            SET attempts = attempts + 1, last_error = $2
+            WHERE id = $1
+            "#,
+            id as i64,
+            error,
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(())
+    }
+}
+
+/// 集約が発行したドメインイベントを、アウトボックスに記録する。
+///
+/// 集約の変更と同一のトランザクションで記録するため、集約の変更に使用しているデータベース
+/// 接続を受け取る。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `events` - 集約が発行したドメインイベント
+///
+/// # 戻り値
+///
+/// `()`
+pub(crate) async fn insert_events(
+    conn: &mut PgConnection,
+    events: &[DomainEvent],
+) -> DomainResult<()> {
+    for event in events {
+        let payload = serde_json::to_string(&PlainDomainEvent::from(event))
+            .map_err(|e| DomainError::Unexpected(e.into()))?;
+        sqlx::query!(
+            r#"
+            INSERT INTO outbox (event_type, aggregate_id, payload, occurred_at)
+            VALUES ($1, $2, $3::TEXT::JSONB, CURRENT_TIMESTAMP)
+            "#,
+            event.code(),
+            event.aggregate_id(),
+            payload,
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+    }
+
+    Ok(())
+}
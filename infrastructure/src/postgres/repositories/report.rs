@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{acquire_connection, PgExecutor};
+use crate::postgres::stored_vegetable_name;
+use domain::repositories::report::{ReportRepository, SalesSummary, VegetableSales};
+use domain::{DomainError, DomainResult};
+
+/// 野菜ごとの売上レコード
+struct VegetableSalesRecord {
+    vegetable_id: Uuid,
+    vegetable_name: String,
+    quantity: i64,
+    weight: Option<i64>,
+    revenue: i64,
+}
+
+impl TryFrom<VegetableSalesRecord> for VegetableSales {
+    type Error = DomainError;
+
+    fn try_from(value: VegetableSalesRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            vegetable_id: value.vegetable_id.into(),
+            vegetable_name: stored_vegetable_name(value.vegetable_id, value.vegetable_name)?,
+            quantity: value.quantity as u64,
+            weight: value.weight.map(|w| w as u64),
+            revenue: value.revenue as u64,
+        })
+    }
+}
+
+/// PostgreSQL用の売上集計リポジトリ
+#[derive(Clone, Debug)]
+pub struct PgReportRepository {
+    executor: PgExecutor,
+}
+
+impl PgReportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+}
+
+#[async_trait]
+impl ReportRepository for PgReportRepository {
+    /// 販売日時が指定した期間の売上を集計する。
+    ///
+    /// 集計はデータベースで実行して、販売を読み込まない。売上金額は、期間の売上金額と
+    /// 野菜ごとの売上金額のどちらも、販売明細の小計を合計した金額とする。
+    ///
+    /// # 引数
+    ///
+    /// * `from` - 集計する期間の開始日時（この日時を含む）
+    /// * `to` - 集計する期間の終了日時（この日時を含まない）
+    ///
+    /// # 戻り値
+    ///
+    /// 期間の売上
+    async fn summarize_sales(
+        &self,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> DomainResult<SalesSummary> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let totals = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) AS "number_of_sales!",
+                COALESCE(SUM(subtotal), 0) AS "total_revenue!"
+            FROM sales
+            WHERE sold_at >= $1 AND sold_at < $2
+            "#,
+            from,
+            to,
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        let vegetables = sqlx::query_as!(
+            VegetableSalesRecord,
+            r#"
+            SELECT
+                v.id AS vegetable_id,
+                v.name AS vegetable_name,
+                COALESCE(SUM(sd.sold_quantity) FILTER (WHERE sd.unit <> 'weight'), 0)
+                    AS "quantity!",
+                SUM(sd.sold_quantity::BIGINT * sd.sold_weight)
+                    FILTER (WHERE sd.unit = 'weight')::BIGINT AS weight,
+                SUM(sd.subtotal)::BIGINT AS "revenue!"
+            FROM sale_details sd
+            INNER JOIN sales s ON s.id = sd.sale_id
+            INNER JOIN vegetables v ON v.id = sd.vegetable_id
+            WHERE s.sold_at >= $1 AND s.sold_at < $2
+            GROUP BY v.id, v.name
+            ORDER BY 5 DESC, v.name
+            "#,
+            from,
+            to,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?
+        .into_iter()
+        .map(VegetableSales::try_from)
+        .collect::<DomainResult<_>>()?;
+
+        Ok(SalesSummary {
+            number_of_sales: totals.number_of_sales as u64,
+            total_revenue: totals.total_revenue as u64,
+            vegetables,
+        })
+    }
+}
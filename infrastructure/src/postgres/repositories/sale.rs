@@ -0,0 +1,711 @@
+use std::collections::{BTreeMap, HashMap};
+
+use async_trait::async_trait;
+use sqlx::postgres::{PgConnection, PgPool};
+use sqlx::{Postgres, QueryBuilder};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::audit::{insert_audit_log, to_snapshot, AuditLogEntry};
+use super::inventory::change_stocks;
+use super::outbox::insert_events;
+use super::{
+    acquire_connection, begin_transaction, commit_transaction, unit_of_work_transaction,
+    PgExecutor, SharedTransaction,
+};
+use crate::postgres::{stored_data_error, PlainSale, PlainVegetable};
+use domain::models::audit::{AuditEntityType, AuditOperation};
+use domain::models::primitives::{Actor, Weight};
+use domain::models::sales::{Sale, SaleDetail, SaleId};
+use domain::models::tax::{RoundingMode, TaxInclusion, TaxPolicy, TaxRate};
+use domain::models::unit::UnitOfMeasure;
+use domain::models::vegetable::Vegetable;
+use domain::repositories::sale::SaleRepository;
+use domain::{DomainError, DomainResult};
+
+/// 販売レコード
+struct SaleRecord {
+    id: Uuid,
+    sold_at: OffsetDateTime,
+    tax_inclusion: String,
+    tax_rounding: String,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+/// 販売した野菜を結合した販売明細レコード
+struct SaleDetailRecord {
+    id: Uuid,
+    sale_id: Uuid,
+    vegetable_id: Uuid,
+    sold_unit_price: i32,
+    unit: String,
+    unit_grams: Option<i32>,
+    sold_quantity: i32,
+    sold_weight: Option<i32>,
+    tax_rate: String,
+    vegetable_name: String,
+    vegetable_unit_price: i32,
+    vegetable_tax_rate: String,
+    vegetable_unit: String,
+    vegetable_unit_grams: Option<i32>,
+    vegetable_category_id: Option<Uuid>,
+    vegetable_tags: Vec<String>,
+    vegetable_available_months: Vec<i16>,
+    vegetable_active: bool,
+    vegetable_version: i32,
+    vegetable_created_at: OffsetDateTime,
+    vegetable_updated_at: OffsetDateTime,
+}
+
+impl TryFrom<SaleDetailRecord> for SaleDetail {
+    type Error = DomainError;
+
+    fn try_from(value: SaleDetailRecord) -> Result<Self, Self::Error> {
+        let vegetable = Vegetable::try_from(PlainVegetable {
+            id: value.vegetable_id,
+            name: value.vegetable_name,
+            unit_price: value.vegetable_unit_price,
+            tax_rate: value.vegetable_tax_rate,
+            unit: value.vegetable_unit,
+            unit_grams: value.vegetable_unit_grams,
+            category_id: value.vegetable_category_id,
+            tags: value.vegetable_tags,
+            available_months: value.vegetable_available_months,
+            active: value.vegetable_active,
+            version: value.vegetable_version,
+            created_at: value.vegetable_created_at,
+            updated_at: value.vegetable_updated_at,
+        })?;
+        let id = value.id;
+        let invalid = |e| stored_data_error("販売明細", id, e);
+        let unit_grams = value
+            .unit_grams
+            .map(Weight::try_from)
+            .transpose()
+            .map_err(invalid)?;
+        let sold_weight = value
+            .sold_weight
+            .map(Weight::try_from)
+            .transpose()
+            .map_err(invalid)?;
+
+        Self::reconstruct(
+            id.into(),
+            vegetable,
+            value.sold_unit_price.try_into().map_err(invalid)?,
+            UnitOfMeasure::from_code(value.unit.as_str(), unit_grams).map_err(invalid)?,
+            value.sold_quantity.try_into().map_err(invalid)?,
+            sold_weight,
+            TaxRate::try_from(value.tax_rate.as_str()).map_err(invalid)?,
+        )
+        .map_err(invalid)
+    }
+}
+
+/// PostgreSQL用の販売リポジトリ
+#[derive(Clone, Debug)]
+pub struct PgSaleRepository {
+    executor: PgExecutor,
+}
+
+impl PgSaleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+
+    /// ユニットオブワークのトランザクションに参加するリポジトリを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `tx` - ユニットオブワークが共有するトランザクション
+    ///
+    /// # 戻り値
+    ///
+    /// リポジトリ
+    pub(crate) fn in_unit_of_work(tx: SharedTransaction) -> Self {
+        Self {
+            executor: PgExecutor::UnitOfWork(tx),
+        }
+    }
+}
+
+#[async_trait]
+impl SaleRepository for PgSaleRepository {
+    /// 販売IDで指定した販売を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売ID
+    ///
+    /// # 戻り値
+    ///
+    /// 販売
+    async fn find_by_id(&self, id: SaleId) -> DomainResult<Option<Sale>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+
+        fetch_sale(&mut conn, id.value()).await
+    }
+
+    /// 販売IDで指定した販売を排他ロックして検索する。
+    ///
+    /// ロックはユニットオブワークのトランザクションが終了するまで保持される。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売ID
+    ///
+    /// # 戻り値
+    ///
+    /// 販売
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Unexpected` - ユニットオブワークに参加していないリポジトリで検索した
+    ///   場合
+    async fn find_by_id_for_update(&self, id: SaleId) -> DomainResult<Option<Sale>> {
+        let mut tx = unit_of_work_transaction(&self.executor).await?;
+        if !lock_sale(&mut tx, id.value()).await? {
+            return Ok(None);
+        }
+
+        fetch_sale(&mut tx, id.value()).await
+    }
+
+    /// すべての販売を検索する。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売のベクタ
+    async fn find_all(&self) -> DomainResult<Vec<Sale>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let records = sqlx::query_as!(
+            SaleRecord,
+            r#"
+            SELECT id, sold_at, tax_inclusion, tax_rounding, created_at, updated_at
+            FROM sales
+            ORDER BY sold_at, id
+            "#,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        let ids: Vec<Uuid> = records.iter().map(|r| r.id).collect();
+        let mut details = fetch_sale_details(&mut conn, &ids).await?;
+
+        records
+            .into_iter()
+            .map(|r| {
+                let sale_details = details.remove(&r.id).unwrap_or_default();
+                build_sale(r, sale_details)
+            })
+            .collect()
+    }
+
+    /// 販売を登録する。
+    ///
+    /// 販売と販売明細を、同一のトランザクションで登録する。
+    /// 小計と税率ごとの消費税は、集計に使用できるように販売に記録する。
+    /// 在庫を管理している野菜は、同一のトランザクションで販売した数量を在庫から引き当てる。
+    /// 販売が発行したドメインイベントは、同一のトランザクションでアウトボックスに記録する。
+    ///
+    /// # 引数
+    ///
+    /// * `sale` - 登録する販売
+    /// * `actor` - 販売を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した販売
+    async fn register(&self, sale: Sale, actor: &Actor) -> DomainResult<Sale> {
+        let id = sale.id().value();
+        let subtotal = i32::try_from(sale.subtotal())?;
+        let total_price = i32::try_from(sale.total_price())?;
+        let reduced = tax_columns(&sale, TaxRate::Reduced)?;
+        let standard = tax_columns(&sale, TaxRate::Standard)?;
+        let mut tx = begin_transaction(&self.executor).await?;
+        sqlx::query!(
+            r#"
+            INSERT INTO sales (
+                id, sold_at, tax_inclusion, tax_rounding, subtotal,
+                reduced_taxable_amount, reduced_tax_amount,
+                standard_taxable_amount, standard_tax_amount,
+                total_price, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+            id,
+            sale.sold_at(),
+            sale.tax_policy().inclusion.code(),
+            sale.tax_policy().rounding.code(),
+            subtotal,
+            reduced.0,
+            reduced.1,
+            standard.0,
+            standard.1,
+            total_price,
+            sale.created_at(),
+            sale.updated_at(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        insert_sale_details(&mut tx, id, sale.sale_details()).await?;
+        let mut changes = BTreeMap::new();
+        add_stock_changes(&mut changes, sale.sale_details(), -1);
+        change_stocks(&mut tx, &changes).await?;
+        let after = fetch_snapshot(&mut tx, id).await?;
+        record_audit_log(&mut tx, id, AuditOperation::Register, None, after, actor).await?;
+        insert_events(&mut tx, sale.events()).await?;
+        let sale = fetch_sale(&mut tx, id).await?.ok_or_else(|| {
+            DomainError::Unexpected(anyhow::anyhow!("登録した販売が見つかりません。"))
+        })?;
+        commit_transaction(tx).await?;
+
+        Ok(sale)
+    }
+
+    /// 販売の販売明細を置き換える。
+    ///
+    /// 既存の販売明細をすべて削除して、引数の販売が持つ販売明細を登録するとともに、
+    /// 販売の小計、税率ごとの消費税及び合計販売金額を更新する。
+    /// 在庫を管理している野菜は、同一のトランザクションで置き換える前後の数量の差を
+    /// 在庫に反映する。販売が発行したドメインイベントは、同一のトランザクションで
+    /// アウトボックスに記録する。
+    ///
+    /// # 引数
+    ///
+    /// * `sale` - 販売明細を置き換える販売
+    /// * `actor` - 販売明細を置き換える利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 販売明細を置き換えた販売
+    async fn replace_details(&self, sale: Sale, actor: &Actor) -> DomainResult<Option<Sale>> {
+        let id = sale.id().value();
+        let subtotal = i32::try_from(sale.subtotal())?;
+        let total_price = i32::try_from(sale.total_price())?;
+        let reduced = tax_columns(&sale, TaxRate::Reduced)?;
+        let standard = tax_columns(&sale, TaxRate::Standard)?;
+        let mut tx = begin_transaction(&self.executor).await?;
+        let before = lock_snapshot(&mut tx, id).await?;
+        let result = sqlx::query!(
+            r#"
+            UPDATE sales
+            SET
+                subtotal = $2,
+                reduced_taxable_amount = $3, reduced_tax_amount = $4,
+                standard_taxable_amount = $5, standard_tax_amount = $6,
+                total_price = $7, updated_at = $8
+            WHERE id = $1
+            "#,
+            id,
+            subtotal,
+            reduced.0,
+            reduced.1,
+            standard.0,
+            standard.1,
+            total_price,
+            sale.updated_at(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        let old_details = fetch_sale_details(&mut tx, &[id])
+            .await?
+            .remove(&id)
+            .unwrap_or_default();
+        let mut changes = BTreeMap::new();
+        add_stock_changes(&mut changes, &old_details, 1);
+        add_stock_changes(&mut changes, sale.sale_details(), -1);
+        sqlx::query!(
+            r#"
+            DELETE FROM sale_details
+            WHERE sale_id = $1
+            "#,
+            id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        insert_sale_details(&mut tx, id, sale.sale_details()).await?;
+        change_stocks(&mut tx, &changes).await?;
+        let after = fetch_snapshot(&mut tx, id).await?;
+        record_audit_log(
+            &mut tx,
+            id,
+            AuditOperation::ReplaceDetails,
+            before,
+            after,
+            actor,
+        )
+        .await?;
+        insert_events(&mut tx, sale.events()).await?;
+        let sale = fetch_sale(&mut tx, id).await?;
+        commit_transaction(tx).await?;
+
+        Ok(sale)
+    }
+
+    /// 販売を削除する。
+    ///
+    /// 販売明細は、外部キー制約により販売と一緒に削除される。
+    /// 在庫を管理している野菜は、同一のトランザクションで販売した数量を在庫に戻す。
+    /// 販売が発行したドメインイベントは、同一のトランザクションでアウトボックスに記録する。
+    ///
+    /// # 引数
+    ///
+    /// * `sale` - 削除する販売
+    /// * `actor` - 販売を削除する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 影響した行数。
+    async fn delete(&self, sale: &Sale, actor: &Actor) -> DomainResult<u32> {
+        let id = sale.id();
+        let mut tx = begin_transaction(&self.executor).await?;
+        let before = lock_snapshot(&mut tx, id.value()).await?;
+        let sale_details = fetch_sale_details(&mut tx, &[id.value()])
+            .await?
+            .remove(&id.value())
+            .unwrap_or_default();
+        let result = {
+            sqlx::query!(
+                r#"
+                DELETE FROM sales
+                WHERE id = $1
+                "#,
+                id.value(),
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?
+        };
+        if 0 < result.rows_affected() {
+            let mut changes = BTreeMap::new();
+            add_stock_changes(&mut changes, &sale_details, 1);
+            change_stocks(&mut tx, &changes).await?;
+            record_audit_log(
+                &mut tx,
+                id.value(),
+                AuditOperation::Delete,
+                before,
+                None,
+                actor,
+            )
+            .await?;
+            insert_events(&mut tx, sale.events()).await?;
+        }
+        commit_transaction(tx).await?;
+
+        Ok(result.rows_affected() as u32)
+    }
+}
+
+/// 販売IDで指定した販売を、販売明細とともに取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 販売ID
+///
+/// # 戻り値
+///
+/// 販売
+async fn fetch_sale(conn: &mut PgConnection, id: Uuid) -> DomainResult<Option<Sale>> {
+    let record = sqlx::query_as!(
+        SaleRecord,
+        r#"
+        SELECT id, sold_at, tax_inclusion, tax_rounding, created_at, updated_at
+        FROM sales
+        WHERE id = $1
+        "#,
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+    let record = match record {
+        Some(record) => record,
+        None => return Ok(None),
+    };
+    let mut details = fetch_sale_details(conn, &[id]).await?;
+    let sale_details = details.remove(&id).unwrap_or_default();
+
+    build_sale(record, sale_details).map(Some)
+}
+
+/// 販売IDで指定した販売を、監査ログに記録する形式で取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 販売ID
+///
+/// # 戻り値
+///
+/// 販売を表すJSON
+async fn fetch_snapshot(conn: &mut PgConnection, id: Uuid) -> DomainResult<Option<String>> {
+    fetch_sale(conn, id)
+        .await?
+        .map(|sale| to_snapshot(&PlainSale::from(sale)))
+        .transpose()
+}
+
+/// 販売IDで指定した販売を排他ロックする。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 販売ID
+///
+/// # 戻り値
+///
+/// 販売が存在してロックした場合は`true`、販売が存在しない場合は`false`
+async fn lock_sale(conn: &mut PgConnection, id: Uuid) -> DomainResult<bool> {
+    let locked = sqlx::query_scalar!(
+        r#"
+        SELECT id FROM sales WHERE id = $1 FOR UPDATE
+        "#,
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(locked.is_some())
+}
+
+/// 販売IDで指定した販売を排他ロックして、監査ログに記録する形式で取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 販売ID
+///
+/// # 戻り値
+///
+/// 販売を表すJSON
+async fn lock_snapshot(conn: &mut PgConnection, id: Uuid) -> DomainResult<Option<String>> {
+    if !lock_sale(conn, id).await? {
+        return Ok(None);
+    }
+
+    fetch_snapshot(conn, id).await
+}
+
+/// 販売の変更を監査ログに記録する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 変更した販売の販売ID
+/// * `operation` - 操作
+/// * `before` - 変更前の販売を表すJSON
+/// * `after` - 変更後の販売を表すJSON
+/// * `actor` - 販売を変更した利用者
+///
+/// # 戻り値
+///
+/// `()`
+async fn record_audit_log(
+    conn: &mut PgConnection,
+    id: Uuid,
+    operation: AuditOperation,
+    before: Option<String>,
+    after: Option<String>,
+    actor: &Actor,
+) -> DomainResult<()> {
+    insert_audit_log(
+        conn,
+        AuditLogEntry {
+            entity_type: AuditEntityType::Sale,
+            entity_id: id,
+            operation,
+            before,
+            after,
+            actor,
+        },
+    )
+    .await
+}
+
+/// 販売IDで指定した販売の販売明細を、販売した野菜とともに取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `sale_ids` - 販売IDのスライス
+///
+/// # 戻り値
+///
+/// 販売IDをキー、販売明細のベクタを値としたハッシュマップ
+async fn fetch_sale_details(
+    conn: &mut PgConnection,
+    sale_ids: &[Uuid],
+) -> DomainResult<HashMap<Uuid, Vec<SaleDetail>>> {
+    let records = sqlx::query_as!(
+        SaleDetailRecord,
+        r#"
+        SELECT
+            sd.id, sd.sale_id, sd.vegetable_id, sd.sold_unit_price, sd.unit, sd.unit_grams,
+            sd.sold_quantity, sd.sold_weight, sd.tax_rate,
+            v.name AS "vegetable_name!", v.unit_price AS "vegetable_unit_price!",
+            v.tax_rate AS "vegetable_tax_rate!", v.unit AS "vegetable_unit!",
+            v.unit_grams AS vegetable_unit_grams, v.category_id AS vegetable_category_id,
+            v.tags AS "vegetable_tags!", v.available_months AS "vegetable_available_months!",
+            v.active AS "vegetable_active!", v.version AS "vegetable_version!",
+            v.created_at AS "vegetable_created_at!", v.updated_at AS "vegetable_updated_at!"
+        FROM sale_details sd
+        INNER JOIN vegetables_with_unit_price v ON v.id = sd.vegetable_id
+        WHERE sd.sale_id = ANY($1)
+        ORDER BY sd.sale_id, sd.id
+        "#,
+        sale_ids,
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    let mut details: HashMap<Uuid, Vec<SaleDetail>> = HashMap::new();
+    for record in records {
+        details
+            .entry(record.sale_id)
+            .or_default()
+            .push(record.try_into()?);
+    }
+
+    Ok(details)
+}
+
+/// 販売明細を登録する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `sale_id` - 販売明細を登録する販売の販売ID
+/// * `sale_details` - 登録する販売明細
+///
+/// # 戻り値
+///
+/// `()`
+async fn insert_sale_details(
+    conn: &mut PgConnection,
+    sale_id: Uuid,
+    sale_details: &[SaleDetail],
+) -> DomainResult<()> {
+    if sale_details.is_empty() {
+        return Ok(());
+    }
+    // 販売明細の小計は、販売の合計販売金額以下であるため`INTEGER`型で表現できる
+    let subtotals = sale_details
+        .iter()
+        .map(|sd| i32::try_from(sd.subtotal()))
+        .collect::<DomainResult<Vec<_>>>()?;
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+        "INSERT INTO sale_details \
+         (id, sale_id, vegetable_id, sold_unit_price, unit, unit_grams, \
+          sold_quantity, sold_weight, tax_rate, subtotal) ",
+    );
+    builder.push_values(
+        sale_details.iter().zip(subtotals),
+        |mut b, (sd, subtotal)| {
+            b.push_bind(sd.id().value())
+                .push_bind(sale_id)
+                .push_bind(sd.vegetable().id().value())
+                .push_bind(i32::from(sd.sold_unit_price()))
+                .push_bind(sd.unit().code())
+                .push_bind(sd.unit().grams().map(i32::from))
+                .push_bind(i32::from(sd.sold_quantity()))
+                .push_bind(sd.sold_weight().map(i32::from))
+                .push_bind(sd.tax_rate().code())
+                .push_bind(subtotal);
+        },
+    );
+    builder
+        .build()
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(())
+}
+
+/// 販売レコードと販売明細から販売を構築する。
+///
+/// # 引数
+///
+/// * `record` - 販売レコード
+/// * `sale_details` - 販売明細
+///
+/// # 戻り値
+///
+/// 販売
+///
+/// # エラー
+///
+/// * `DomainError::Unexpected` - 販売レコードがドメインルールを満たしていない場合
+fn build_sale(record: SaleRecord, sale_details: Vec<SaleDetail>) -> DomainResult<Sale> {
+    let id = record.id;
+    let invalid = |e| stored_data_error("販売", id, e);
+    let tax_policy = TaxPolicy {
+        inclusion: TaxInclusion::try_from(record.tax_inclusion.as_str()).map_err(invalid)?,
+        rounding: RoundingMode::try_from(record.tax_rounding.as_str()).map_err(invalid)?,
+    };
+
+    Sale::reconstruct(
+        record.id.into(),
+        record.sold_at,
+        sale_details,
+        tax_policy,
+        record.created_at,
+        record.updated_at,
+    )
+    .map_err(invalid)
+}
+
+/// 販売に記録する、指定した税率の対象額と消費税額を返す。
+///
+/// # 引数
+///
+/// * `sale` - 販売
+/// * `rate` - 税率
+///
+/// # 戻り値
+///
+/// 対象額と消費税額のタプル（指定した税率の販売明細がない場合はどちらも0）
+///
+/// # エラー
+///
+/// * `DomainError::Validation` - 金額が`INTEGER`型で表現できる範囲を超える場合
+fn tax_columns(sale: &Sale, rate: TaxRate) -> DomainResult<(i32, i32)> {
+    match sale.taxes().iter().find(|t| t.rate() == rate) {
+        Some(t) => Ok((
+            i32::try_from(t.taxable_amount())?,
+            i32::try_from(t.tax_amount())?,
+        )),
+        None => Ok((0, 0)),
+    }
+}
+
+/// 販売明細の数量を、野菜ごとの在庫の増減に加える。
+///
+/// 量り売りする野菜は、販売した重さ（グラム）で在庫を増減する。
+///
+/// # 引数
+///
+/// * `changes` - 野菜IDをキー、在庫の増減を値とした順序付きマップ
+/// * `sale_details` - 販売明細
+/// * `sign` - 在庫を増やす場合は`1`、減らす場合は`-1`
+fn add_stock_changes(changes: &mut BTreeMap<Uuid, i64>, sale_details: &[SaleDetail], sign: i64) {
+    for sd in sale_details {
+        *changes.entry(sd.vegetable().id().value()).or_default() +=
+            // 数量と重さはいずれも`INTEGER`型の最大値以下のため、積は`i64`で表現できる
+            sign * sd.stock_quantity() as i64;
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use sqlx::{Postgres, Transaction};
+use tokio::sync::Mutex;
+
+use super::inventory::PgInventoryRepository;
+use super::sale::PgSaleRepository;
+use super::vegetable::PgVegetableRepository;
+use super::SharedTransaction;
+use domain::repositories::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use domain::{DomainError, DomainResult};
+
+/// PostgreSQL用のユニットオブワーク
+///
+/// ユニットオブワークが提供するリポジトリは、ユニットオブワークが開始したトランザクションを
+/// 共有する。リポジトリのメソッドはトランザクションを排他的に使用するため、同じユニット
+/// オブワークのリポジトリのメソッドを並行して呼び出さないこと。
+#[derive(Debug)]
+pub struct PgUnitOfWork {
+    /// リポジトリが共有するトランザクション
+    tx: SharedTransaction,
+    /// 野菜リポジトリ
+    vegetable: PgVegetableRepository,
+    /// 販売リポジトリ
+    sale: PgSaleRepository,
+    /// 在庫リポジトリ
+    inventory: PgInventoryRepository,
+}
+
+impl PgUnitOfWork {
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `tx` - リポジトリが共有するトランザクション
+    ///
+    /// # 戻り値
+    ///
+    /// ユニットオブワーク
+    fn new(tx: Transaction<'static, Postgres>) -> Self {
+        let tx = Arc::new(Mutex::new(Some(tx)));
+
+        Self {
+            vegetable: PgVegetableRepository::in_unit_of_work(tx.clone()),
+            sale: PgSaleRepository::in_unit_of_work(tx.clone()),
+            inventory: PgInventoryRepository::in_unit_of_work(tx.clone()),
+            tx,
+        }
+    }
+
+    /// リポジトリが共有していたトランザクションを取り出す。
+    ///
+    /// 複製されたリポジトリがトランザクションを参照していても取り出せる。取り出した後に
+    /// 複製されたリポジトリのメソッドを呼び出すと、エラーを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// トランザクション
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Unexpected` - トランザクションが取り出されている場合
+    async fn into_transaction(self) -> DomainResult<Transaction<'static, Postgres>> {
+        self.tx.lock().await.take().ok_or_else(|| {
+            DomainError::Unexpected(anyhow::anyhow!(
+                "ユニットオブワークのトランザクションは終了しています。"
+            ))
+        })
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PgUnitOfWork {
+    type Vegetable = PgVegetableRepository;
+    type Sale = PgSaleRepository;
+    type Inventory = PgInventoryRepository;
+
+    /// トランザクションに参加する野菜リポジトリを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜リポジトリ
+    fn vegetable(&self) -> &Self::Vegetable {
+        &self.vegetable
+    }
+
+    /// トランザクションに参加する販売リポジトリを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売リポジトリ
+    fn sale(&self) -> &Self::Sale {
+        &self.sale
+    }
+
+    /// トランザクションに参加する在庫リポジトリを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫リポジトリ
+    fn inventory(&self) -> &Self::Inventory {
+        &self.inventory
+    }
+
+    /// リポジトリによる変更をまとめてコミットする。
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    async fn commit(self) -> DomainResult<()> {
+        self.into_transaction()
+            .await?
+            .commit()
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))
+    }
+
+    /// リポジトリによる変更をまとめてロールバックする。
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    async fn rollback(self) -> DomainResult<()> {
+        self.into_transaction()
+            .await?
+            .rollback()
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))
+    }
+}
+
+/// PostgreSQL用のユニットオブワークファクトリ
+#[derive(Clone, Debug)]
+pub struct PgUnitOfWorkFactory {
+    pool: PgPool,
+}
+
+impl PgUnitOfWorkFactory {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for PgUnitOfWorkFactory {
+    type UnitOfWork = PgUnitOfWork;
+
+    /// トランザクションを開始して、ユニットオブワークを返す。
+    ///
+    /// # 戻り値
+    ///
+    /// ユニットオブワーク
+    async fn begin(&self) -> DomainResult<Self::UnitOfWork> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(PgUnitOfWork::new(tx))
+    }
+}
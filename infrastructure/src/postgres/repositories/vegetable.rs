@@ -1,23 +1,69 @@
 use async_trait::async_trait;
-use sqlx::postgres::PgPool;
+use sqlx::postgres::{PgConnection, PgPool};
 use sqlx::{Postgres, QueryBuilder};
+use time::OffsetDateTime;
 use uuid::Uuid;
 
-use super::{begin_transaction, commit_transaction};
-use crate::postgres::PlainVegetable;
-use domain::models::vegetable::{Vegetable, VegetableId};
-use domain::repositories::vegetable::{PartialVegetable, UpsertVegetable, VegetableRepository};
+use super::audit::{insert_audit_log, to_snapshot, AuditLogEntry};
+use super::outbox::insert_events;
+use super::{
+    acquire_connection, begin_transaction, commit_transaction, unit_of_work_transaction,
+    PgExecutor, SharedTransaction,
+};
+use crate::postgres::{PlainVegetable, PlainVegetablePrice};
+use domain::models::audit::{AuditEntityType, AuditOperation};
+use domain::models::availability::AvailableMonths;
+use domain::models::primitives::{Actor, Price, Tag};
+use domain::models::vegetable::{version_conflict_error, Vegetable, VegetableId, VegetablePrice};
+use domain::repositories::vegetable::{
+    PartialVegetable, SortOrder, UpsertVegetable, VegetablePage, VegetableQuery,
+    VegetableRepository, VegetableSortKey,
+};
 use domain::{DomainError, DomainResult};
 
+/// 野菜価格レコード
+struct VegetablePriceRecord {
+    unit_price: i32,
+    effective_from: OffsetDateTime,
+}
+
+impl From<VegetablePriceRecord> for VegetablePrice {
+    fn from(value: VegetablePriceRecord) -> Self {
+        // 永続化層からのデータはドメインルールを満たしていることを前提とするため、
+        // エラー処理を省略
+        Self::new(value.unit_price.try_into().unwrap(), value.effective_from)
+    }
+}
+
 /// PostgreSQL用の野菜リポジトリ
+///
+/// 野菜の単価は野菜価格テーブルに履歴として記録して、現在の単価を持つ野菜ビューから
+/// 野菜を取得する。
 #[derive(Clone, Debug)]
 pub struct PgVegetableRepository {
-    pool: PgPool,
+    executor: PgExecutor,
 }
 
 impl PgVegetableRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            executor: PgExecutor::Pool(pool),
+        }
+    }
+
+    /// ユニットオブワークのトランザクションに参加するリポジトリを構築する。
+    ///
+    /// # 引数
+    ///
+    /// * `tx` - ユニットオブワークが共有するトランザクション
+    ///
+    /// # 戻り値
+    ///
+    /// リポジトリ
+    pub(crate) fn in_unit_of_work(tx: SharedTransaction) -> Self {
+        Self {
+            executor: PgExecutor::UnitOfWork(tx),
+        }
     }
 }
 
@@ -33,41 +79,96 @@ impl VegetableRepository for PgVegetableRepository {
     ///
     /// 野菜
     async fn find_by_id(&self, id: VegetableId) -> DomainResult<Option<Vegetable>> {
-        let veg = sqlx::query_as!(
-            PlainVegetable,
+        let mut conn = acquire_connection(&self.executor).await?;
+
+        fetch_vegetable(&mut conn, id.value()).await
+    }
+
+    /// 野菜IDで指定した野菜を共有ロックして検索する。
+    ///
+    /// ロックはユニットオブワークのトランザクションが終了するまで保持される。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜
+    ///
+    /// # エラー
+    ///
+    /// * `DomainError::Unexpected` - ユニットオブワークに参加していないリポジトリで検索した
+    ///   場合
+    async fn find_by_id_for_share(&self, id: VegetableId) -> DomainResult<Option<Vegetable>> {
+        let mut tx = unit_of_work_transaction(&self.executor).await?;
+        let locked = sqlx::query_scalar!(
             r#"
-            SELECT id, name, unit_price, created_at, updated_at
-            FROM vegetables
-            WHERE id = $1
+            SELECT id FROM vegetables WHERE id = $1 AND deleted_at IS NULL FOR SHARE
             "#,
             id.value(),
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| DomainError::Unexpected(e.into()))?;
+        if locked.is_none() {
+            return Ok(None);
+        }
 
-        Ok(veg.map(|v| v.into()))
+        fetch_vegetable(&mut tx, id.value()).await
     }
 
-    /// すべての野菜を検索する。
+    /// 検索条件に一致する野菜を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `query` - 野菜の検索条件
     ///
     /// # 戻り値
     ///
-    /// 野菜のベクタ
-    async fn find_all(&self) -> DomainResult<Vec<Vegetable>> {
-        let records = sqlx::query_as!(
-            PlainVegetable,
-            r#"
-            SELECT id, name, unit_price, created_at, updated_at
-            FROM vegetables
-            ORDER BY id
-            "#,
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| DomainError::Unexpected(e.into()))?;
+    /// 野菜の検索結果
+    async fn find_all(&self, query: VegetableQuery) -> DomainResult<VegetablePage> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let mut builder: QueryBuilder<Postgres> =
+            QueryBuilder::new("SELECT COUNT(*) FROM vegetables_with_unit_price");
+        push_conditions(&mut builder, &query);
+        let total: i64 = builder
+            .build_query_scalar()
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?;
 
-        Ok(records.into_iter().map(|v| v.into()).collect())
+        let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT \
+                id, name, unit_price, tax_rate, unit, unit_grams, category_id, tags, \
+                available_months, active, version, created_at, updated_at \
+             FROM vegetables_with_unit_price",
+        );
+        push_conditions(&mut builder, &query);
+        builder.push(match query.sort_key {
+            VegetableSortKey::Name => " ORDER BY name",
+            VegetableSortKey::UnitPrice => " ORDER BY unit_price",
+            VegetableSortKey::CreatedAt => " ORDER BY created_at",
+        });
+        builder.push(match query.sort_order {
+            SortOrder::Asc => " ASC",
+            SortOrder::Desc => " DESC",
+        });
+        builder.push(", id LIMIT ");
+        builder.push_bind(query.limit as i64);
+        builder.push(" OFFSET ");
+        builder.push_bind(query.offset() as i64);
+        let records = builder
+            .build_query_as::<PlainVegetable>()
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?;
+        let items = records
+            .into_iter()
+            .map(Vegetable::try_from)
+            .collect::<DomainResult<_>>()?;
+
+        Ok(VegetablePage::new(&query, items, total as u64))
     }
 
     /// 野菜を登録する。
@@ -75,32 +176,46 @@ impl VegetableRepository for PgVegetableRepository {
     /// # 引数
     ///
     /// * `vegetable` - 登録する野菜
+    /// * `actor` - 野菜を登録する利用者
     ///
     /// # 戻り値
     ///
     /// 登録した野菜
-    async fn register(&self, vegetable: UpsertVegetable) -> DomainResult<Vegetable> {
+    async fn register(&self, vegetable: UpsertVegetable, actor: &Actor) -> DomainResult<Vegetable> {
         let id = Uuid::new_v4();
-        let mut tx = begin_transaction(&self.pool).await?;
-        let veg = {
-            sqlx::query_as!(
-                PlainVegetable,
-                r#"
-                INSERT INTO vegetables (id, name, unit_price, created_at, updated_at)
-                VALUES ($1, $2, $3, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
-                RETURNING id, name, unit_price, created_at, updated_at
-                "#,
-                id,
-                &vegetable.name,
-                vegetable.unit_price.value() as i32,
+        let mut tx = begin_transaction(&self.executor).await?;
+        sqlx::query!(
+            r#"
+            INSERT INTO vegetables (
+                id, name, tax_rate, unit, unit_grams, category_id, tags, available_months,
+                active, created_at, updated_at
             )
-            .fetch_one(&mut *tx)
-            .await
-            .map_err(|e| DomainError::Unexpected(e.into()))?
-        };
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+            )
+            "#,
+            id,
+            vegetable.name.value(),
+            vegetable.tax_rate.code(),
+            vegetable.unit.code(),
+            vegetable.unit.grams().map(i32::from),
+            vegetable.category_id.map(|id| id.value()),
+            &tag_values(&vegetable.tags),
+            &month_values(vegetable.available_months),
+            vegetable.active,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(convert_write_error)?;
+        upsert_price(&mut tx, id, vegetable.unit_price, None).await?;
+        let after = fetch_snapshot(&mut tx, id).await?;
+        record_audit_log(&mut tx, id, AuditOperation::Register, None, after, actor).await?;
+        let veg = fetch_vegetable(&mut tx, id).await?.ok_or_else(|| {
+            DomainError::Unexpected(anyhow::anyhow!("登録した野菜が見つかりません。"))
+        })?;
         commit_transaction(tx).await?;
 
-        Ok(veg.into())
+        Ok(veg)
     }
 
     /// 野菜を更新する。
@@ -108,7 +223,9 @@ impl VegetableRepository for PgVegetableRepository {
     /// # 引数
     ///
     /// * `id` - 更新する野菜の野菜ID
+    /// * `version` - 更新する野菜の版（`None`の場合は版を確認しない）
     /// * `vegetable` - 更新する野菜
+    /// * `actor` - 野菜を更新する利用者
     ///
     /// # 戻り値
     ///
@@ -116,32 +233,54 @@ impl VegetableRepository for PgVegetableRepository {
     async fn update(
         &self,
         id: VegetableId,
+        version: Option<u32>,
         vegetable: UpsertVegetable,
+        actor: &Actor,
     ) -> DomainResult<Option<Vegetable>> {
-        let mut tx = begin_transaction(&self.pool).await?;
-        let veg = {
-            sqlx::query_as!(
-                PlainVegetable,
-                r#"
-                UPDATE vegetables
-                SET name = $2, unit_price = $3, updated_at = CURRENT_TIMESTAMP
-                WHERE id = $1
-                RETURNING id, name, unit_price, created_at, updated_at
-                "#,
-                id.value(),
-                &vegetable.name,
-                vegetable.unit_price.value() as i32,
-            )
-            .fetch_optional(&mut *tx)
-            .await
-            .map_err(|e| DomainError::Unexpected(e.into()))?
-        };
+        let mut tx = begin_transaction(&self.executor).await?;
+        let before = lock_snapshot(&mut tx, id.value()).await?;
+        let result = sqlx::query!(
+            r#"
+            UPDATE vegetables
+            SET
+                name = $2, tax_rate = $3, unit = $4, unit_grams = $5, category_id = $6,
+                tags = $7, available_months = $8, active = $9, version = version + 1,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND deleted_at IS NULL AND ($10::INTEGER IS NULL OR version = $10)
+            "#,
+            id.value(),
+            vegetable.name.value(),
+            vegetable.tax_rate.code(),
+            vegetable.unit.code(),
+            vegetable.unit.grams().map(i32::from),
+            vegetable.category_id.map(|id| id.value()),
+            &tag_values(&vegetable.tags),
+            &month_values(vegetable.available_months),
+            vegetable.active,
+            version.map(version_value),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(convert_write_error)?;
+        if result.rows_affected() == 0 {
+            ensure_not_conflicted(&mut tx, id.value()).await?;
+            return Ok(None);
+        }
+        change_price(&mut tx, id.value(), vegetable.unit_price, None).await?;
+        let after = fetch_snapshot(&mut tx, id.value()).await?;
+        record_audit_log(
+            &mut tx,
+            id.value(),
+            AuditOperation::Update,
+            before,
+            after,
+            actor,
+        )
+        .await?;
+        let veg = fetch_vegetable(&mut tx, id.value()).await?;
         commit_transaction(tx).await?;
 
-        match veg {
-            Some(v) => Ok(Some(v.into())),
-            None => Ok(None),
-        }
+        Ok(veg)
     }
 
     /// 野菜を部分更新する。
@@ -149,7 +288,9 @@ impl VegetableRepository for PgVegetableRepository {
     /// # 引数
     ///
     /// * `id` - 部分更新する野菜の野菜ID
+    /// * `version` - 部分更新する野菜の版（`None`の場合は版を確認しない）
     /// * `vegetable` - 部分更新する野菜
+    /// * `actor` - 野菜を部分更新する利用者
     ///
     /// # 戻り値
     ///
@@ -157,68 +298,793 @@ impl VegetableRepository for PgVegetableRepository {
     async fn partial_update(
         &self,
         id: VegetableId,
+        version: Option<u32>,
         vegetable: PartialVegetable,
+        actor: &Actor,
     ) -> DomainResult<Option<Vegetable>> {
-        if vegetable.name.is_none() && vegetable.unit_price.is_none() {
-            return self.find_by_id(id).await;
+        if vegetable.name.is_none()
+            && vegetable.unit_price.is_none()
+            && vegetable.tax_rate.is_none()
+            && vegetable.unit.is_none()
+            && vegetable.category_id.is_none()
+            && vegetable.tags.is_none()
+            && vegetable.available_months.is_none()
+            && vegetable.active.is_none()
+        {
+            let veg = self.find_by_id(id).await?;
+            if let Some(veg) = &veg {
+                veg.ensure_version(version)?;
+            }
+            return Ok(veg);
         }
         let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("UPDATE vegetables SET");
         if let Some(name) = &vegetable.name {
             builder.push(" name = ");
-            builder.push_bind(name);
+            builder.push_bind(name.value());
             builder.push(", ");
         }
-        if let Some(unit_price) = vegetable.unit_price {
-            builder.push(" unit_price = ");
-            builder.push_bind(unit_price.value() as i32);
+        if let Some(tax_rate) = vegetable.tax_rate {
+            builder.push(" tax_rate = ");
+            builder.push_bind(tax_rate.code());
+            builder.push(", ");
+        }
+        if let Some(unit) = vegetable.unit {
+            builder.push(" unit = ");
+            builder.push_bind(unit.code());
+            builder.push(", unit_grams = ");
+            builder.push_bind(unit.grams().map(i32::from));
             builder.push(", ");
         }
-        builder.push(" updated_at = CURRENT_TIMESTAMP");
+        if let Some(category_id) = vegetable.category_id {
+            builder.push(" category_id = ");
+            builder.push_bind(category_id.map(|id| id.value()));
+            builder.push(", ");
+        }
+        if let Some(tags) = &vegetable.tags {
+            builder.push(" tags = ");
+            builder.push_bind(tag_values(tags));
+            builder.push(", ");
+        }
+        if let Some(available_months) = vegetable.available_months {
+            builder.push(" available_months = ");
+            builder.push_bind(month_values(available_months));
+            builder.push(", ");
+        }
+        if let Some(active) = vegetable.active {
+            builder.push(" active = ");
+            builder.push_bind(active);
+            builder.push(", ");
+        }
+        builder.push(" version = version + 1, updated_at = CURRENT_TIMESTAMP");
         builder.push(" WHERE id = ");
         builder.push_bind(id.value());
-        builder.push(" RETURNING id, name, unit_price, created_at, updated_at");
-
-        let mut tx = begin_transaction(&self.pool).await?;
-        let veg = {
-            builder
-                .build_query_as::<PlainVegetable>()
-                .fetch_optional(&mut *tx)
-                .await
-                .map_err(|e| DomainError::Unexpected(e.into()))?
-        };
+        builder.push(" AND deleted_at IS NULL");
+        if let Some(version) = version {
+            builder.push(" AND version = ");
+            builder.push_bind(version_value(version));
+        }
+
+        let mut tx = begin_transaction(&self.executor).await?;
+        let before = lock_snapshot(&mut tx, id.value()).await?;
+        let result = builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(convert_write_error)?;
+        if result.rows_affected() == 0 {
+            ensure_not_conflicted(&mut tx, id.value()).await?;
+            return Ok(None);
+        }
+        if let Some(unit_price) = vegetable.unit_price {
+            change_price(&mut tx, id.value(), unit_price, None).await?;
+        }
+        let after = fetch_snapshot(&mut tx, id.value()).await?;
+        record_audit_log(
+            &mut tx,
+            id.value(),
+            AuditOperation::PartialUpdate,
+            before,
+            after,
+            actor,
+        )
+        .await?;
+        let veg = fetch_vegetable(&mut tx, id.value()).await?;
         commit_transaction(tx).await?;
 
-        match veg {
-            Some(v) => Ok(Some(v.into())),
-            None => Ok(None),
+        Ok(veg)
+    }
+
+    /// 野菜IDで指定した野菜の価格の履歴を、適用開始日時の順に検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の価格のベクタ
+    async fn find_prices(&self, id: VegetableId) -> DomainResult<Option<Vec<VegetablePrice>>> {
+        let mut conn = acquire_connection(&self.executor).await?;
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM vegetables WHERE id = $1 AND deleted_at IS NULL
+            ) AS "exists!"
+            "#,
+            id.value(),
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        if !exists {
+            return Ok(None);
+        }
+        let records = sqlx::query_as!(
+            VegetablePriceRecord,
+            r#"
+            SELECT unit_price, effective_from
+            FROM vegetable_prices
+            WHERE vegetable_id = $1
+            ORDER BY effective_from
+            "#,
+            id.value(),
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+        Ok(Some(records.into_iter().map(|r| r.into()).collect()))
+    }
+
+    /// 野菜IDで指定した野菜の価格を登録する。
+    ///
+    /// 現在の単価と同じ単価を現在から適用する場合は、価格の履歴を記録しないため、野菜の版を
+    /// 増やさず、監査ログも記録しない。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `price` - 登録する野菜の価格
+    /// * `actor` - 野菜の価格を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した野菜の価格
+    async fn register_price(
+        &self,
+        id: VegetableId,
+        price: VegetablePrice,
+        actor: &Actor,
+    ) -> DomainResult<Option<VegetablePrice>> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let locked = sqlx::query_scalar!(
+            r#"
+            SELECT id FROM vegetables WHERE id = $1 AND deleted_at IS NULL FOR UPDATE
+            "#,
+            id.value(),
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        if locked.is_none() {
+            return Ok(None);
         }
+        let current = fetch_current_price(&mut tx, id.value()).await?;
+        let changed = change_price(
+            &mut tx,
+            id.value(),
+            price.unit_price(),
+            Some(price.effective_from()),
+        )
+        .await?;
+        if !changed {
+            commit_transaction(tx).await?;
+            return Ok(Some(price));
+        }
+        sqlx::query!(
+            r#"
+            UPDATE vegetables
+            SET version = version + 1, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            "#,
+            id.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        // 適用開始日時が未来の価格は野菜の単価に反映されないため、変更前は登録する前の現在の
+        // 価格、変更後は登録した価格を記録する
+        let before = current
+            .map(|p| to_snapshot(&PlainVegetablePrice::from(p)))
+            .transpose()?;
+        let after = to_snapshot(&PlainVegetablePrice::from(price))?;
+        record_audit_log(
+            &mut tx,
+            id.value(),
+            AuditOperation::RegisterPrice,
+            before,
+            Some(after),
+            actor,
+        )
+        .await?;
+        commit_transaction(tx).await?;
+
+        Ok(Some(price))
     }
 
-    /// 野菜IDで指定した野菜を削除する。
+    /// 野菜IDで指定した野菜を論理削除する。
     ///
     /// # 引数
     ///
     /// * `id` - 野菜ID
+    /// * `version` - 論理削除する野菜の版（`None`の場合は版を確認しない）
+    /// * `actor` - 野菜を論理削除する利用者
     ///
     /// # 戻り値
     ///
     /// 影響した行数。
-    async fn delete(&self, id: VegetableId) -> DomainResult<u32> {
-        let mut tx = begin_transaction(&self.pool).await?;
+    async fn delete(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        actor: &Actor,
+    ) -> DomainResult<u32> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let before = lock_snapshot(&mut tx, id.value()).await?;
         let result = {
             sqlx::query!(
                 r#"
-                DELETE FROM vegetables
-                WHERE id = $1
+                UPDATE vegetables
+                SET
+                    deleted_at = CURRENT_TIMESTAMP, version = version + 1,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE id = $1 AND deleted_at IS NULL AND ($2::INTEGER IS NULL OR version = $2)
                 "#,
                 id.value(),
+                version.map(version_value),
             )
             .execute(&mut *tx)
             .await
             .map_err(|e| DomainError::Unexpected(e.into()))?
         };
+        if result.rows_affected() == 0 {
+            ensure_not_conflicted(&mut tx, id.value()).await?;
+        } else {
+            record_audit_log(
+                &mut tx,
+                id.value(),
+                AuditOperation::Delete,
+                before,
+                None,
+                actor,
+            )
+            .await?;
+        }
+        commit_transaction(tx).await?;
+
+        Ok(result.rows_affected() as u32)
+    }
+
+    /// 野菜IDで指定した、論理削除した野菜を復元する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `actor` - 野菜を復元する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 復元した野菜
+    async fn restore(&self, id: VegetableId, actor: &Actor) -> DomainResult<Option<Vegetable>> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        let before = lock_snapshot(&mut tx, id.value()).await?;
+        let result = sqlx::query!(
+            r#"
+            UPDATE vegetables
+            SET deleted_at = NULL, version = version + 1, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            "#,
+            id.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(convert_write_error)?;
+        if 0 < result.rows_affected() {
+            let after = fetch_snapshot(&mut tx, id.value()).await?;
+            record_audit_log(
+                &mut tx,
+                id.value(),
+                AuditOperation::Restore,
+                before,
+                after,
+                actor,
+            )
+            .await?;
+        }
+        let veg = fetch_vegetable(&mut tx, id.value()).await?;
+        commit_transaction(tx).await?;
+
+        Ok(veg)
+    }
+
+    /// 野菜IDで指定した野菜を物理削除する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `version` - 物理削除する野菜の版（`None`の場合は版を確認しない）
+    /// * `actor` - 野菜を物理削除する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 影響した行数。
+    async fn purge(
+        &self,
+        id: VegetableId,
+        version: Option<u32>,
+        actor: &Actor,
+    ) -> DomainResult<u32> {
+        let mut tx = begin_transaction(&self.executor).await?;
+        // 論理削除した野菜も物理削除するため、論理削除したかによらず野菜の版を確認する
+        let current = sqlx::query_scalar!(
+            r#"
+            SELECT version FROM vegetables WHERE id = $1 FOR UPDATE
+            "#,
+            id.value(),
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        match (current, version) {
+            (None, _) => return Ok(0),
+            (Some(current), Some(version)) if current != version_value(version) => {
+                return Err(version_conflict_error())
+            }
+            _ => {}
+        }
+        let referenced = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (SELECT 1 FROM sale_details WHERE vegetable_id = $1) AS "exists!"
+            "#,
+            id.value(),
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Unexpected(e.into()))?;
+        if referenced {
+            return Err(referenced_by_sales_error());
+        }
+        let before = fetch_snapshot(&mut tx, id.value()).await?;
+        // 確認した後に販売明細が登録された場合は、外部キー制約により物理削除に失敗する
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM vegetables
+            WHERE id = $1
+            "#,
+            id.value(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| match e.as_database_error() {
+            Some(db_error) if db_error.is_foreign_key_violation() => referenced_by_sales_error(),
+            _ => DomainError::Unexpected(e.into()),
+        })?;
+        record_audit_log(
+            &mut tx,
+            id.value(),
+            AuditOperation::Purge,
+            before,
+            None,
+            actor,
+        )
+        .await?;
         commit_transaction(tx).await?;
 
         Ok(result.rows_affected() as u32)
     }
 }
+
+/// 野菜IDで指定した、論理削除していない野菜を、現在の単価とともに取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// 野菜
+async fn fetch_vegetable(conn: &mut PgConnection, id: Uuid) -> DomainResult<Option<Vegetable>> {
+    let veg = sqlx::query_as!(
+        PlainVegetable,
+        r#"
+        SELECT
+            id AS "id!", name AS "name!", unit_price AS "unit_price!", tax_rate AS "tax_rate!",
+            unit AS "unit!", unit_grams, category_id, tags AS "tags!",
+            available_months AS "available_months!", active AS "active!", version AS "version!",
+            created_at AS "created_at!", updated_at AS "updated_at!"
+        FROM vegetables_with_unit_price
+        WHERE id = $1 AND deleted_at IS NULL
+        "#,
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    veg.map(Vegetable::try_from).transpose()
+}
+
+/// 野菜IDで指定した野菜を、監査ログに記録する形式で取得する。
+///
+/// 論理削除した野菜も取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// 野菜を表すJSON
+async fn fetch_snapshot(conn: &mut PgConnection, id: Uuid) -> DomainResult<Option<String>> {
+    let veg = sqlx::query_as!(
+        PlainVegetable,
+        r#"
+        SELECT
+            id AS "id!", name AS "name!", unit_price AS "unit_price!", tax_rate AS "tax_rate!",
+            unit AS "unit!", unit_grams, category_id, tags AS "tags!",
+            available_months AS "available_months!", active AS "active!", version AS "version!",
+            created_at AS "created_at!", updated_at AS "updated_at!"
+        FROM vegetables_with_unit_price
+        WHERE id = $1
+        "#,
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    veg.as_ref().map(to_snapshot).transpose()
+}
+
+/// 野菜IDで指定した野菜を排他ロックして、監査ログに記録する形式で取得する。
+///
+/// 変更前の野菜を記録した後に、他のトランザクションが野菜を変更しないように、
+/// 論理削除したかによらず野菜をロックする。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// 野菜を表すJSON
+async fn lock_snapshot(conn: &mut PgConnection, id: Uuid) -> DomainResult<Option<String>> {
+    let locked = sqlx::query_scalar!(
+        r#"
+        SELECT id FROM vegetables WHERE id = $1 FOR UPDATE
+        "#,
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+    if locked.is_none() {
+        return Ok(None);
+    }
+
+    fetch_snapshot(conn, id).await
+}
+
+/// 野菜の変更を監査ログに記録する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 変更した野菜の野菜ID
+/// * `operation` - 操作
+/// * `before` - 変更前の野菜を表すJSON
+/// * `after` - 変更後の野菜を表すJSON
+/// * `actor` - 野菜を変更した利用者
+///
+/// # 戻り値
+///
+/// `()`
+async fn record_audit_log(
+    conn: &mut PgConnection,
+    id: Uuid,
+    operation: AuditOperation,
+    before: Option<String>,
+    after: Option<String>,
+    actor: &Actor,
+) -> DomainResult<()> {
+    insert_audit_log(
+        conn,
+        AuditLogEntry {
+            entity_type: AuditEntityType::Vegetable,
+            entity_id: id,
+            operation,
+            before,
+            after,
+            actor,
+        },
+    )
+    .await
+}
+
+/// 野菜の価格を登録する。
+///
+/// 同じ適用開始日時の価格が既に登録されている場合は、その価格の単価を置き換える。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+/// * `unit_price` - 単価
+/// * `effective_from` - 適用開始日時（`None`の場合はトランザクションを開始した日時）
+///
+/// # 戻り値
+///
+/// `()`
+async fn upsert_price(
+    conn: &mut PgConnection,
+    id: Uuid,
+    unit_price: Price,
+    effective_from: Option<OffsetDateTime>,
+) -> DomainResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO vegetable_prices (
+            vegetable_id, effective_from, unit_price, created_at, updated_at
+        )
+        VALUES ($1, COALESCE($2, CURRENT_TIMESTAMP), $3, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+        ON CONFLICT (vegetable_id, effective_from)
+        DO UPDATE SET unit_price = EXCLUDED.unit_price, updated_at = EXCLUDED.updated_at
+        "#,
+        id,
+        effective_from,
+        i32::from(unit_price),
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(())
+}
+
+/// 野菜の現在の価格を取得する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// 適用開始日時がトランザクションを開始した日時以前の、最新の価格（価格の履歴がない場合は
+/// `None`）
+async fn fetch_current_price(
+    conn: &mut PgConnection,
+    id: Uuid,
+) -> DomainResult<Option<VegetablePrice>> {
+    let record = sqlx::query_as!(
+        VegetablePriceRecord,
+        r#"
+        SELECT unit_price, effective_from
+        FROM vegetable_prices
+        WHERE vegetable_id = $1 AND effective_from <= CURRENT_TIMESTAMP
+        ORDER BY effective_from DESC
+        LIMIT 1
+        "#,
+        id,
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+
+    Ok(record.map(|r| r.into()))
+}
+
+/// 野菜の価格を変更して、野菜が発行したドメインイベントをアウトボックスに記録する。
+///
+/// 現在の単価と同じ単価を現在から適用する場合は、価格の履歴を記録しない。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+/// * `unit_price` - 単価
+/// * `effective_from` - 適用開始日時（`None`の場合はトランザクションを開始した日時）
+///
+/// # 戻り値
+///
+/// 価格の履歴を記録した場合は`true`、現在の単価と同じ単価を現在から適用する場合は`false`
+async fn change_price(
+    conn: &mut PgConnection,
+    id: Uuid,
+    unit_price: Price,
+    effective_from: Option<OffsetDateTime>,
+) -> DomainResult<bool> {
+    let effective_from = match effective_from {
+        Some(effective_from) => effective_from,
+        None => sqlx::query_scalar!(r#"SELECT CURRENT_TIMESTAMP AS "now!""#)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| DomainError::Unexpected(e.into()))?,
+    };
+    let mut veg = fetch_vegetable(conn, id).await?.ok_or_else(|| {
+        DomainError::Unexpected(anyhow::anyhow!("価格を変更する野菜が見つかりません。"))
+    })?;
+    if !veg.change_price(VegetablePrice::new(unit_price, effective_from)) {
+        return Ok(false);
+    }
+    upsert_price(conn, id, unit_price, Some(effective_from)).await?;
+    insert_events(conn, veg.events()).await?;
+
+    Ok(true)
+}
+
+/// 野菜の検索条件をWHERE句としてクエリに追加する。
+///
+/// 論理削除した野菜は、検索条件によらず検索の対象としない。
+///
+/// # 引数
+///
+/// * `builder` - クエリビルダー
+/// * `query` - 野菜の検索条件
+fn push_conditions(builder: &mut QueryBuilder<'_, Postgres>, query: &VegetableQuery) {
+    builder.push(" WHERE deleted_at IS NULL");
+    if let Some(name) = &query.name {
+        builder.push(" AND name ILIKE ");
+        builder.push_bind(format!("%{}%", escape_like(name)));
+    }
+    if let Some(min_unit_price) = query.min_unit_price {
+        builder.push(" AND unit_price >= ");
+        builder.push_bind(i64::from(min_unit_price));
+    }
+    if let Some(max_unit_price) = query.max_unit_price {
+        builder.push(" AND unit_price <= ");
+        builder.push_bind(i64::from(max_unit_price));
+    }
+    if let Some(category_id) = query.category_id {
+        builder.push(" AND category_id = ");
+        builder.push_bind(category_id.value());
+    }
+    if let Some(tag) = &query.tag {
+        builder.push(" AND ");
+        builder.push_bind(tag.value().to_string());
+        builder.push(" = ANY(tags)");
+    }
+    if let Some(month) = query.available_in {
+        builder.push(" AND active AND ");
+        builder.push_bind(u8::from(month) as i16);
+        builder.push(" = ANY(available_months)");
+    }
+}
+
+/// タグを、データベースに格納する文字列のベクタに変換する。
+///
+/// # 引数
+///
+/// * `tags` - タグのスライス
+///
+/// # 戻り値
+///
+/// タグを表す文字列のベクタ
+fn tag_values(tags: &[Tag]) -> Vec<String> {
+    tags.iter().map(|t| t.value().to_string()).collect()
+}
+
+/// 販売する月を、データベースに格納する整数のベクタに変換する。
+///
+/// # 引数
+///
+/// * `months` - 販売する月
+///
+/// # 戻り値
+///
+/// 1から12までの整数で表した月のベクタ
+fn month_values(months: AvailableMonths) -> Vec<i16> {
+    months.months().into_iter().map(i16::from).collect()
+}
+
+/// LIKE演算子のパターンで特別な意味を持つ文字をエスケープする。
+///
+/// # 引数
+///
+/// * `value` - エスケープする文字列
+///
+/// # 戻り値
+///
+/// エスケープした文字列
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// 野菜を更新または削除できなかった時に、他の更新と競合していないか確認する。
+///
+/// 論理削除していない野菜が存在する場合は、野菜の版が指定した版と一致しなかったため、
+/// 他の更新と競合したと判断する。
+///
+/// # 引数
+///
+/// * `conn` - データベース接続
+/// * `id` - 野菜ID
+///
+/// # 戻り値
+///
+/// `()`
+///
+/// # エラー
+///
+/// * `DomainError::Conflict` - 野菜が他の更新と競合した場合
+async fn ensure_not_conflicted(conn: &mut PgConnection, id: Uuid) -> DomainResult<()> {
+    let exists = sqlx::query_scalar!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM vegetables WHERE id = $1 AND deleted_at IS NULL
+        ) AS "exists!"
+        "#,
+        id,
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| DomainError::Unexpected(e.into()))?;
+    if exists {
+        return Err(version_conflict_error());
+    }
+
+    Ok(())
+}
+
+/// 野菜の版を、データベースに格納する整数に変換する。
+///
+/// 野菜の版は`INTEGER`型で表現できる最大値を超えないため、範囲外の版は一致しない版として
+/// 扱う。
+///
+/// # 引数
+///
+/// * `version` - 野菜の版
+///
+/// # 戻り値
+///
+/// 野菜の版を表す整数
+fn version_value(version: u32) -> i32 {
+    i32::try_from(version).unwrap_or(-1)
+}
+
+/// 販売明細が参照している野菜を物理削除しようとした時のエラーを返す。
+///
+/// # 戻り値
+///
+/// `DomainError::DomainRule`
+fn referenced_by_sales_error() -> DomainError {
+    DomainError::DomainRule("販売で参照されている野菜は物理削除できません。".into())
+}
+
+/// 野菜を書き込んだときに発生したエラーを、ドメインエラーに変換する。
+///
+/// # 引数
+///
+/// * `e` - 野菜を書き込んだときに発生したエラー
+///
+/// # 戻り値
+///
+/// 野菜名の一意制約に違反した場合は`DomainError::DomainRule`、カテゴリの外部キー制約に
+/// 違反した場合は`DomainError::Validation`、それ以外の場合は`DomainError::Unexpected`
+fn convert_write_error(e: sqlx::Error) -> DomainError {
+    match e.as_database_error() {
+        Some(db_error) if db_error.is_unique_violation() => {
+            DomainError::DomainRule("同じ名前の野菜が既に登録されています。".into())
+        }
+        Some(db_error) if db_error.is_foreign_key_violation() => {
+            DomainError::Validation("カテゴリが見つかりません。".into())
+        }
+        _ => DomainError::Unexpected(e.into()),
+    }
+}
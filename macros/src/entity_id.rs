@@ -7,6 +7,17 @@ pub(crate) fn derive_entity_id_impl(input: DeriveInput) -> syn::Result<TokenStre
 
     Ok(quote! {
         impl #ident {
+            /// 新しいエンティティIDを生成する。
+            ///
+            /// # 戻り値
+            ///
+            /// UUIDv4で生成したエンティティID
+            pub fn generate() -> Self {
+                Self {
+                    value: uuid::Uuid::new_v4(),
+                }
+            }
+
             /// エンティティIDをUUIDで返却する。
             ///
             /// # 戻り値
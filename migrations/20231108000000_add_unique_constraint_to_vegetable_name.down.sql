@@ -0,0 +1,2 @@
+-- 野菜名の一意制約を削除（正規化した野菜名は元に戻さない）
+ALTER TABLE vegetables DROP CONSTRAINT IF EXISTS vegetables_name_key;
@@ -0,0 +1,18 @@
+-- 野菜名を、野菜名の値オブジェクトと同じ規則で正規化（Unicode正規化形式KCで正規化して、前後の空白を除く）
+UPDATE vegetables
+SET name = btrim(left(btrim(normalize(name, NFKC), E' \t\n\r\f'), 80))
+WHERE name <> btrim(left(btrim(normalize(name, NFKC), E' \t\n\r\f'), 80));
+-- 空または空白のみの野菜名を、野菜IDを含む野菜名に置き換え
+UPDATE vegetables
+SET name = '名称未設定 (' || id::TEXT || ')'
+WHERE name = '';
+-- 正規化により重複した野菜名を、最も古い野菜を除いて、野菜IDを付加した野菜名に置き換え
+UPDATE vegetables v
+SET name = btrim(left(v.name, 41)) || ' (' || v.id::TEXT || ')'
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY name ORDER BY created_at, id) AS rn
+    FROM vegetables
+) d
+WHERE v.id = d.id AND 1 < d.rn;
+-- 野菜名に一意制約を追加
+ALTER TABLE vegetables ADD CONSTRAINT vegetables_name_key UNIQUE (name);
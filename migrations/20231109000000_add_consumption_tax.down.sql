@@ -0,0 +1,13 @@
+-- 販売から消費税の計算方法と金額を削除
+ALTER TABLE sales
+    DROP COLUMN standard_tax_amount,
+    DROP COLUMN standard_taxable_amount,
+    DROP COLUMN reduced_tax_amount,
+    DROP COLUMN reduced_taxable_amount,
+    DROP COLUMN subtotal,
+    DROP COLUMN tax_rounding,
+    DROP COLUMN tax_inclusion;
+-- 販売明細から消費税率を削除
+ALTER TABLE sale_details DROP COLUMN tax_rate;
+-- 野菜から消費税率を削除
+ALTER TABLE vegetables DROP COLUMN tax_rate;
@@ -0,0 +1,34 @@
+-- 野菜に消費税率を追加
+ALTER TABLE vegetables
+    ADD COLUMN tax_rate VARCHAR(16) NOT NULL DEFAULT 'reduced'
+        CHECK (tax_rate IN ('reduced', 'standard'));
+-- 販売明細に販売した時の消費税率を追加
+ALTER TABLE sale_details
+    ADD COLUMN tax_rate VARCHAR(16) NOT NULL DEFAULT 'reduced'
+        CHECK (tax_rate IN ('reduced', 'standard'));
+ALTER TABLE sale_details ALTER COLUMN tax_rate DROP DEFAULT;
+-- 販売に消費税の計算方法と金額を追加
+-- 既存の販売は、税込価格の軽減税率で、消費税を切り捨てたものとして扱う
+ALTER TABLE sales
+    ADD COLUMN tax_inclusion VARCHAR(16) NOT NULL DEFAULT 'inclusive'
+        CHECK (tax_inclusion IN ('inclusive', 'exclusive')),
+    ADD COLUMN tax_rounding VARCHAR(16) NOT NULL DEFAULT 'floor'
+        CHECK (tax_rounding IN ('floor', 'round', 'ceil')),
+    ADD COLUMN subtotal INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN reduced_taxable_amount INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN reduced_tax_amount INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN standard_taxable_amount INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN standard_tax_amount INTEGER NOT NULL DEFAULT 0;
+UPDATE sales
+SET
+    subtotal = total_price,
+    reduced_taxable_amount = total_price,
+    reduced_tax_amount = total_price * 8 / 108;
+ALTER TABLE sales
+    ALTER COLUMN tax_inclusion DROP DEFAULT,
+    ALTER COLUMN tax_rounding DROP DEFAULT,
+    ALTER COLUMN subtotal DROP DEFAULT,
+    ALTER COLUMN reduced_taxable_amount DROP DEFAULT,
+    ALTER COLUMN reduced_tax_amount DROP DEFAULT,
+    ALTER COLUMN standard_taxable_amount DROP DEFAULT,
+    ALTER COLUMN standard_tax_amount DROP DEFAULT;
@@ -0,0 +1,15 @@
+-- 現在の単価を持つ野菜ビュー削除
+DROP VIEW IF EXISTS vegetables_with_unit_price;
+-- 野菜テーブルに現在の単価を戻す
+ALTER TABLE vegetables ADD COLUMN unit_price INTEGER NOT NULL DEFAULT 0;
+UPDATE vegetables v
+SET unit_price = COALESCE((
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+), 0);
+ALTER TABLE vegetables ALTER COLUMN unit_price DROP DEFAULT;
+-- 野菜価格テーブル削除
+DROP TABLE IF EXISTS vegetable_prices;
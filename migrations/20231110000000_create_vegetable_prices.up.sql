@@ -0,0 +1,28 @@
+-- 野菜価格テーブル作成
+CREATE TABLE IF NOT EXISTS vegetable_prices (
+    vegetable_id UUID NOT NULL,
+    effective_from TIMESTAMP WITH TIME ZONE NOT NULL,
+    unit_price INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (vegetable_id, effective_from),
+    FOREIGN KEY (vegetable_id) REFERENCES vegetables (id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+-- 既存の野菜の単価を、野菜を作成した日時から適用する価格として記録
+INSERT INTO vegetable_prices (vegetable_id, effective_from, unit_price, created_at, updated_at)
+SELECT id, created_at, unit_price, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+FROM vegetables;
+-- 野菜の単価は価格の履歴から求めるため、野菜テーブルから削除
+ALTER TABLE vegetables DROP COLUMN unit_price;
+-- 現在の単価を持つ野菜ビュー作成
+-- 単価は、適用開始日時が現在以前の価格のうち、適用開始日時が最も新しい価格の単価とする
+CREATE VIEW vegetables_with_unit_price AS
+SELECT v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
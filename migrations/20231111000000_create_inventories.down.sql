@@ -0,0 +1,2 @@
+-- 在庫テーブル削除
+DROP TABLE IF EXISTS inventories;
@@ -0,0 +1,10 @@
+-- 在庫テーブル作成
+-- 在庫テーブルに記録されていない野菜は、在庫が無限にあるものとして扱う
+CREATE TABLE IF NOT EXISTS inventories (
+    vegetable_id UUID NOT NULL,
+    quantity INTEGER NOT NULL CHECK (quantity >= 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (vegetable_id),
+    FOREIGN KEY (vegetable_id) REFERENCES vegetables (id) ON DELETE CASCADE ON UPDATE CASCADE
+);
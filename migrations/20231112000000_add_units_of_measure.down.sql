@@ -0,0 +1,22 @@
+-- 現在の単価を持つ野菜ビューから、販売する単位を削除
+DROP VIEW IF EXISTS vegetables_with_unit_price;
+CREATE VIEW vegetables_with_unit_price AS
+SELECT v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
+-- 販売明細から販売した時の単位、量り売りした重さ及び小計を削除
+ALTER TABLE sale_details
+    DROP COLUMN subtotal,
+    DROP COLUMN sold_weight,
+    DROP COLUMN unit_grams,
+    DROP COLUMN unit;
+-- 野菜から販売する単位を削除
+ALTER TABLE vegetables
+    DROP COLUMN unit_grams,
+    DROP COLUMN unit;
@@ -0,0 +1,34 @@
+-- 野菜に販売する単位を追加
+-- 量り売り（weight）の野菜は、単価の基準となる重さ（グラム）を持つ
+ALTER TABLE vegetables
+    ADD COLUMN unit VARCHAR(16) NOT NULL DEFAULT 'piece'
+        CHECK (unit IN ('piece', 'bundle', 'bag', 'weight')),
+    ADD COLUMN unit_grams INTEGER CHECK (unit_grams > 0),
+    ADD CHECK ((unit = 'weight') = (unit_grams IS NOT NULL));
+-- 販売明細に販売した時の単位、量り売りした重さ及び小計を追加
+-- 量り売りした重さは、野菜1つあたりの重さ（グラム）とする
+ALTER TABLE sale_details
+    ADD COLUMN unit VARCHAR(16) NOT NULL DEFAULT 'piece'
+        CHECK (unit IN ('piece', 'bundle', 'bag', 'weight')),
+    ADD COLUMN unit_grams INTEGER CHECK (unit_grams > 0),
+    ADD COLUMN sold_weight INTEGER CHECK (sold_weight > 0),
+    ADD COLUMN subtotal INTEGER NOT NULL DEFAULT 0,
+    ADD CHECK ((unit = 'weight') = (unit_grams IS NOT NULL)),
+    ADD CHECK ((unit = 'weight') = (sold_weight IS NOT NULL));
+UPDATE sale_details SET subtotal = sold_unit_price * sold_quantity;
+ALTER TABLE sale_details
+    ALTER COLUMN unit DROP DEFAULT,
+    ALTER COLUMN subtotal DROP DEFAULT;
+-- 現在の単価を持つ野菜ビューに、販売する単位を追加
+CREATE OR REPLACE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
@@ -0,0 +1,22 @@
+-- 現在の単価を持つ野菜ビューから、カテゴリとタグを削除
+DROP VIEW IF EXISTS vegetables_with_unit_price;
+CREATE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
+-- 野菜からカテゴリとタグを削除
+DROP INDEX IF EXISTS vegetables_tags_idx;
+DROP INDEX IF EXISTS vegetables_category_id_idx;
+ALTER TABLE vegetables
+    DROP COLUMN tags,
+    DROP COLUMN category_id;
+-- カテゴリテーブル削除
+DROP TABLE IF EXISTS categories;
@@ -0,0 +1,30 @@
+-- カテゴリテーブル作成
+CREATE TABLE IF NOT EXISTS categories (
+    id UUID NOT NULL,
+    name VARCHAR(40) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id),
+    CONSTRAINT categories_name_key UNIQUE (name)
+);
+-- 野菜に、野菜を分類するカテゴリとタグを追加
+-- カテゴリを削除した場合、そのカテゴリに分類されていた野菜はカテゴリに分類されていない野菜になる
+ALTER TABLE vegetables
+    ADD COLUMN category_id UUID
+        REFERENCES categories (id) ON DELETE SET NULL ON UPDATE CASCADE,
+    ADD COLUMN tags VARCHAR(30)[] NOT NULL DEFAULT '{}';
+CREATE INDEX IF NOT EXISTS vegetables_category_id_idx ON vegetables (category_id);
+CREATE INDEX IF NOT EXISTS vegetables_tags_idx ON vegetables USING GIN (tags);
+-- 現在の単価を持つ野菜ビューに、カテゴリとタグを追加
+CREATE OR REPLACE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams, v.category_id, v.tags
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
@@ -0,0 +1,23 @@
+-- 野菜に、販売する月（1月から12月）と販売中かどうかを追加
+-- 既存の野菜は、通年で販売中とする
+ALTER TABLE vegetables
+    ADD COLUMN available_months SMALLINT[] NOT NULL
+        DEFAULT '{1,2,3,4,5,6,7,8,9,10,11,12}'
+        CHECK (
+            0 < cardinality(available_months)
+            AND available_months <@ '{1,2,3,4,5,6,7,8,9,10,11,12}'::SMALLINT[]
+        ),
+    ADD COLUMN active BOOLEAN NOT NULL DEFAULT TRUE;
+-- 現在の単価を持つ野菜ビューに、販売する月と販売中かどうかを追加
+CREATE OR REPLACE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams, v.category_id, v.tags, v.available_months, v.active
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
@@ -0,0 +1,26 @@
+-- 現在の単価を持つ野菜ビューから、野菜を論理削除した日時を削除
+DROP VIEW IF EXISTS vegetables_with_unit_price;
+CREATE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams, v.category_id, v.tags, v.available_months, v.active
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
+-- 販売明細の外部キー制約を元に戻す
+ALTER TABLE sale_details
+    DROP CONSTRAINT IF EXISTS sale_details_vegetable_id_fkey,
+    ADD CONSTRAINT sale_details_vegetable_id_fkey
+        FOREIGN KEY (vegetable_id) REFERENCES vegetables (id)
+        ON DELETE CASCADE ON UPDATE CASCADE;
+-- 野菜名の一意インデックスを一意制約に戻す
+-- 論理削除した野菜と同じ野菜名の野菜が存在する場合は失敗するため、事前に野菜名を変更すること
+DROP INDEX IF EXISTS vegetables_name_key;
+ALTER TABLE vegetables ADD CONSTRAINT vegetables_name_key UNIQUE (name);
+-- 野菜から、野菜を論理削除した日時を削除
+ALTER TABLE vegetables DROP COLUMN deleted_at;
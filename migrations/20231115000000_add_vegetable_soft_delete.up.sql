@@ -0,0 +1,26 @@
+-- 野菜に、野菜を論理削除した日時を追加
+ALTER TABLE vegetables ADD COLUMN deleted_at TIMESTAMP WITH TIME ZONE;
+-- 野菜名の一意制約を、論理削除していない野菜の野菜名のみを対象とする一意インデックスに置き換え
+ALTER TABLE vegetables DROP CONSTRAINT IF EXISTS vegetables_name_key;
+CREATE UNIQUE INDEX IF NOT EXISTS vegetables_name_key
+    ON vegetables (name) WHERE deleted_at IS NULL;
+-- 販売明細が参照している野菜を物理削除できないように、外部キー制約を変更
+ALTER TABLE sale_details
+    DROP CONSTRAINT IF EXISTS sale_details_vegetable_id_fkey,
+    ADD CONSTRAINT sale_details_vegetable_id_fkey
+        FOREIGN KEY (vegetable_id) REFERENCES vegetables (id)
+        ON DELETE RESTRICT ON UPDATE CASCADE;
+-- 現在の単価を持つ野菜ビューに、野菜を論理削除した日時を追加
+-- 過去の販売明細から論理削除した野菜を参照できるように、ビューには論理削除した野菜を含める
+CREATE OR REPLACE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams, v.category_id, v.tags, v.available_months, v.active, v.deleted_at
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
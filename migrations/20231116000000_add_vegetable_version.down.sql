@@ -0,0 +1,16 @@
+-- 現在の単価を持つ野菜ビューから、野菜の版を削除
+DROP VIEW IF EXISTS vegetables_with_unit_price;
+CREATE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams, v.category_id, v.tags, v.available_months, v.active, v.deleted_at
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
+-- 野菜から、野菜の版を削除
+ALTER TABLE vegetables DROP COLUMN version;
@@ -0,0 +1,17 @@
+-- 野菜に、楽観的排他制御に使用する版を追加
+-- 版は野菜を更新するたびに1ずつ増やす
+ALTER TABLE vegetables ADD COLUMN version INTEGER NOT NULL DEFAULT 1 CHECK (0 < version);
+-- 現在の単価を持つ野菜ビューに、野菜の版を追加
+CREATE OR REPLACE VIEW vegetables_with_unit_price AS
+SELECT
+    v.id, v.name, p.unit_price, v.tax_rate, v.created_at, v.updated_at,
+    v.unit, v.unit_grams, v.category_id, v.tags, v.available_months, v.active, v.deleted_at,
+    v.version
+FROM vegetables v
+INNER JOIN LATERAL (
+    SELECT vp.unit_price
+    FROM vegetable_prices vp
+    WHERE vp.vegetable_id = v.id AND vp.effective_from <= CURRENT_TIMESTAMP
+    ORDER BY vp.effective_from DESC
+    LIMIT 1
+) p ON TRUE;
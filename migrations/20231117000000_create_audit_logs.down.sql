@@ -0,0 +1,3 @@
+-- 監査ログテーブル削除
+DROP TABLE IF EXISTS audit_logs;
+DROP FUNCTION IF EXISTS reject_audit_log_changes();
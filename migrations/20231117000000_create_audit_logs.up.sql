@@ -0,0 +1,30 @@
+-- 監査ログテーブル作成
+-- 変更前と変更後の状態は、APIが返す形式のJSONで記録する（登録した場合の変更前と、
+-- 削除した場合の変更後はNULL）
+CREATE TABLE IF NOT EXISTS audit_logs (
+    id BIGINT GENERATED ALWAYS AS IDENTITY,
+    entity_type VARCHAR(16) NOT NULL CHECK (entity_type IN ('vegetable', 'sale')),
+    entity_id UUID NOT NULL,
+    operation VARCHAR(32) NOT NULL
+        CHECK (
+            operation IN (
+                'register', 'update', 'partial_update', 'register_price',
+                'delete', 'restore', 'purge', 'replace_details'
+            )
+        ),
+    before JSONB,
+    after JSONB,
+    actor VARCHAR(100) NOT NULL,
+    recorded_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX IF NOT EXISTS audit_logs_entity_idx ON audit_logs (entity_type, entity_id);
+-- 監査ログは追記のみとするため、監査ログの更新と削除を禁止
+CREATE OR REPLACE FUNCTION reject_audit_log_changes() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION '監査ログは更新または削除できません。';
+END;
+$$ LANGUAGE plpgsql;
+CREATE TRIGGER audit_logs_append_only
+    BEFORE UPDATE OR DELETE ON audit_logs
+    FOR EACH ROW EXECUTE FUNCTION reject_audit_log_changes();
@@ -0,0 +1,23 @@
+-- アウトボックステーブル作成
+-- 集約が発行したドメインイベントを、集約の変更と同一のトランザクションで記録して、
+-- ディスパッチャーが記録した順に配信する
+CREATE TABLE IF NOT EXISTS outbox (
+    id BIGINT GENERATED ALWAYS AS IDENTITY,
+    event_type VARCHAR(32) NOT NULL
+        CHECK (
+            event_type IN (
+                'vegetable_price_changed', 'sale_completed', 'item_returned', 'sale_cancelled'
+            )
+        ),
+    aggregate_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    -- 配信した日時（配信していない場合はNULL）
+    processed_at TIMESTAMP WITH TIME ZONE,
+    -- 配信を試みた回数と、最後に配信に失敗したときのエラー
+    attempts INTEGER NOT NULL DEFAULT 0 CHECK (0 <= attempts),
+    last_error TEXT,
+    PRIMARY KEY (id)
+);
+-- 配信していないドメインイベントを、記録した順に検索するためのインデックス
+CREATE INDEX IF NOT EXISTS outbox_pending_idx ON outbox (id) WHERE processed_at IS NULL;
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::UsecaseResult;
+use domain::models::events::{OutboxEvent, PendingEvent};
+use domain::repositories::outbox::OutboxRepository;
+
+/// ドメインイベントハンドラー
+///
+/// ドメインイベントは、少なくとも1回配信されるため、同じドメインイベントを複数回処理しても
+/// 結果が変わらないように実装すること。
+#[async_trait]
+pub trait DomainEventHandler: Send + Sync {
+    /// ドメインイベントハンドラーの名前を返す。
+    fn name(&self) -> &'static str;
+
+    /// アウトボックスに記録されたドメインイベントを処理する。
+    async fn handle(&self, event: &OutboxEvent) -> UsecaseResult<()>;
+}
+
+/// ドメインイベントを配信した結果
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DispatchReport {
+    /// すべてのドメインイベントハンドラーが処理したドメインイベントの数
+    pub processed: usize,
+    /// いずれかのドメインイベントハンドラーが処理に失敗したドメインイベントの数
+    pub failed: usize,
+}
+
+/// ドメインイベントディスパッチャー
+///
+/// アウトボックスに記録された、配信していないドメインイベントを記録した順に取得して、
+/// 登録されたドメインイベントハンドラーに配信する。すべてのドメインイベントハンドラーが
+/// 処理したドメインイベントは、配信したことを記録する。いずれかのドメインイベントハンドラーが
+/// 処理に失敗したドメインイベントは、配信に失敗したことを記録して、次回の配信で
+/// すべてのドメインイベントハンドラーに再度配信する。ドメインイベントに変換できなかった
+/// ドメインイベントは、ドメインイベントハンドラーに配信せずに、配信に失敗したことを記録する。
+pub struct EventDispatcher<R>
+where
+    R: OutboxRepository,
+{
+    /// アウトボックスリポジトリ
+    repository: R,
+    /// ドメインイベントハンドラー
+    handlers: Vec<Arc<dyn DomainEventHandler>>,
+}
+
+impl<R> EventDispatcher<R>
+where
+    R: OutboxRepository,
+{
+    /// 1回の配信で取得するドメインイベントの最大数
+    pub const BATCH_SIZE: u32 = 100;
+    /// ドメインイベントの配信を試みる最大回数
+    pub const MAX_ATTEMPTS: u32 = 5;
+
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `repository` - アウトボックスリポジトリ
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントディスパッチャー
+    pub fn new(repository: R) -> Self {
+        Self {
+            repository,
+            handlers: Vec::new(),
+        }
+    }
+
+    /// ドメインイベントハンドラーを登録する。
+    ///
+    /// # 引数
+    ///
+    /// * `handler` - ドメインイベントハンドラー
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントハンドラーを登録したドメインイベントディスパッチャー
+    pub fn register<H>(mut self, handler: H) -> Self
+    where
+        H: DomainEventHandler + 'static,
+    {
+        self.handlers.push(Arc::new(handler));
+
+        self
+    }
+
+    /// 配信していないドメインイベントを、登録されたドメインイベントハンドラーに配信する。
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントを配信した結果
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    pub async fn dispatch_pending(&self) -> UsecaseResult<DispatchReport> {
+        let events = self
+            .repository
+            .find_pending(Self::BATCH_SIZE, Self::MAX_ATTEMPTS)
+            .await?;
+        let mut report = DispatchReport::default();
+        for event in events {
+            let id = event.id();
+            let error = match event {
+                PendingEvent::Decoded(event) => self.handle(&event).await,
+                PendingEvent::Undecodable { reason, .. } => {
+                    Some(format!("ドメインイベントに変換できません。{}", reason))
+                }
+            };
+            match error {
+                None => {
+                    self.repository.mark_processed(id).await?;
+                    report.processed += 1;
+                }
+                Some(error) => {
+                    self.repository.mark_failed(id, &error).await?;
+                    report.failed += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// ドメインイベントを、登録されたドメインイベントハンドラーに順に配信する。
+    ///
+    /// # 引数
+    ///
+    /// * `event` - アウトボックスに記録されたドメインイベント
+    ///
+    /// # 戻り値
+    ///
+    /// いずれかのドメインイベントハンドラーが処理に失敗した場合は、失敗したドメインイベント
+    /// ハンドラーの名前とエラー（すべてのドメインイベントハンドラーが処理した場合は`None`）
+    async fn handle(&self, event: &OutboxEvent) -> Option<String> {
+        for handler in &self.handlers {
+            if let Err(e) = handler.handle(event).await {
+                return Some(format!("{}: {}", handler.name(), e));
+            }
+        }
+
+        None
+    }
+}
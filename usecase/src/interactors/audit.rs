@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{UsecaseError, UsecaseResult};
+use domain::models::audit::{AuditEntityType, AuditLog};
+use domain::repositories::audit::{AuditLogQuery, AuditLogRepository};
+
+/// 監査ログの検索条件
+#[derive(Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogListInput {
+    /// 変更したエンティティの種類（`vegetable`または`sale`）
+    pub entity: Option<String>,
+    /// 変更したエンティティのID
+    pub entity_id: Option<String>,
+    /// 取得する監査ログの数
+    pub limit: Option<u32>,
+}
+
+impl TryFrom<AuditLogListInput> for AuditLogQuery {
+    type Error = UsecaseError;
+
+    fn try_from(value: AuditLogListInput) -> Result<Self, Self::Error> {
+        let limit = value.limit.unwrap_or(Self::DEFAULT_LIMIT);
+        if !(1..=Self::MAX_LIMIT).contains(&limit) {
+            return Err(UsecaseError::Validation(
+                format!(
+                    "取得する監査ログの数は1以上{}以下で指定してください。",
+                    Self::MAX_LIMIT
+                )
+                .into(),
+            ));
+        }
+        let entity_id = value
+            .entity_id
+            .as_deref()
+            .map(|id| {
+                Uuid::parse_str(id).map_err(|_| {
+                    UsecaseError::Validation(
+                        "UUIDv4形式の文字列でエンティティのIDを指定してください。".into(),
+                    )
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            entity_type: value
+                .entity
+                .as_deref()
+                .map(AuditEntityType::try_from)
+                .transpose()?,
+            entity_id,
+            limit,
+        })
+    }
+}
+
+/// 監査ログユースケースインタラクター
+#[async_trait]
+pub trait AuditLogInteractor: Clone {
+    /// 検索条件に一致する監査ログを検索する。
+    async fn find_all(&self, input: AuditLogListInput) -> UsecaseResult<Vec<AuditLog>>;
+}
+
+/// 監査ログユースケースインタラクターの実装
+///
+/// 監査ログリポジトリを通じて監査ログを検索するため、監査ログリポジトリを実装した任意の
+/// 永続化層で使用できる。
+#[derive(Clone, Debug)]
+pub struct AuditLogInteractorImpl<R>
+where
+    R: AuditLogRepository,
+{
+    /// 監査ログリポジトリ
+    repository: R,
+}
+
+impl<R> AuditLogInteractorImpl<R>
+where
+    R: AuditLogRepository,
+{
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `repository` - 監査ログリポジトリ
+    ///
+    /// # 戻り値
+    ///
+    /// 監査ログインタラクター
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> AuditLogInteractor for AuditLogInteractorImpl<R>
+where
+    R: AuditLogRepository + Clone + Send + Sync,
+{
+    /// 検索条件に一致する監査ログを検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `input` - 監査ログの検索条件
+    ///
+    /// # 戻り値
+    ///
+    /// 記録した日時の新しい順に並べた監査ログのベクタ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 検索条件が正しくない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_all(&self, input: AuditLogListInput) -> UsecaseResult<Vec<AuditLog>> {
+        let query: AuditLogQuery = input.try_into()?;
+
+        self.repository.find_all(query).await.map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+
+use crate::{UsecaseError, UsecaseResult};
+use domain::models::category::{Category, CategoryId};
+use domain::repositories::category::{CategoryRepository, UpsertCategory};
+use domain::DomainError;
+
+/// 登録または更新するカテゴリ
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertCategoryInput {
+    /// カテゴリ名
+    pub name: String,
+}
+
+impl TryFrom<UpsertCategoryInput> for UpsertCategory {
+    type Error = DomainError;
+
+    fn try_from(value: UpsertCategoryInput) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name.try_into()?,
+        })
+    }
+}
+
+/// カテゴリユースケースインタラクター
+#[async_trait]
+pub trait CategoryInteractor: Clone {
+    /// カテゴリIDで指定されたカテゴリを検索する。
+    async fn find_by_id(&self, id: &str) -> UsecaseResult<Option<Category>>;
+
+    /// すべてのカテゴリを検索する。
+    async fn find_all(&self) -> UsecaseResult<Vec<Category>>;
+
+    /// カテゴリを登録する。
+    async fn register(&self, input: UpsertCategoryInput) -> UsecaseResult<Category>;
+
+    /// カテゴリを更新する。
+    async fn update(&self, id: &str, input: UpsertCategoryInput)
+        -> UsecaseResult<Option<Category>>;
+
+    /// カテゴリIDで指定したカテゴリを削除する。
+    async fn delete(&self, id: &str) -> UsecaseResult<u32>;
+}
+
+/// カテゴリユースケースインタラクターの実装
+///
+/// カテゴリリポジトリを通じてカテゴリを永続化するため、カテゴリリポジトリを実装した任意の
+/// 永続化層で使用できる。
+#[derive(Clone, Debug)]
+pub struct CategoryInteractorImpl<R>
+where
+    R: CategoryRepository,
+{
+    /// カテゴリリポジトリ
+    repository: R,
+}
+
+impl<R> CategoryInteractorImpl<R>
+where
+    R: CategoryRepository,
+{
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `repository` - カテゴリリポジトリ
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリインタラクター
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> CategoryInteractor for CategoryInteractorImpl<R>
+where
+    R: CategoryRepository + Clone + Send + Sync,
+{
+    /// カテゴリIDで指定したカテゴリを検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - カテゴリID
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数のカテゴリIDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_by_id(&self, id: &str) -> UsecaseResult<Option<Category>> {
+        let id = convert_to_category_id(id)?;
+
+        self.repository.find_by_id(id).await.map_err(|e| e.into())
+    }
+
+    /// すべてのカテゴリを検索する。
+    ///
+    /// # 戻り値
+    ///
+    /// カテゴリ名の順に並べたカテゴリのベクタ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_all(&self) -> UsecaseResult<Vec<Category>> {
+        self.repository.find_all().await.map_err(|e| e.into())
+    }
+
+    /// カテゴリを登録する。
+    ///
+    /// # 引数
+    ///
+    /// * `input` - 登録するカテゴリ
+    ///
+    /// # 戻り値
+    ///
+    /// 登録したカテゴリ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - カテゴリ名が正しくない場合
+    /// * `UsecaseError::DomainRule` - 同じカテゴリ名のカテゴリが既に登録されている場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn register(&self, input: UpsertCategoryInput) -> UsecaseResult<Category> {
+        let input: UpsertCategory = input.try_into()?;
+
+        self.repository.register(input).await.map_err(|e| e.into())
+    }
+
+    /// カテゴリを更新する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - カテゴリID
+    /// * `input` - 更新するカテゴリ
+    ///
+    /// # 戻り値
+    ///
+    /// 更新したカテゴリ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数のカテゴリIDがUUIDv4形式でない場合、または
+    ///   カテゴリ名が正しくない場合
+    /// * `UsecaseError::DomainRule` - 同じカテゴリ名の他のカテゴリが既に登録されている場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn update(
+        &self,
+        id: &str,
+        input: UpsertCategoryInput,
+    ) -> UsecaseResult<Option<Category>> {
+        let id = convert_to_category_id(id)?;
+        let input: UpsertCategory = input.try_into()?;
+
+        self.repository
+            .update(id, input)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// カテゴリIDで指定したカテゴリを削除する。
+    ///
+    /// 削除したカテゴリに分類されていた野菜は、カテゴリに分類されていない野菜になる。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - カテゴリID
+    ///
+    /// # 戻り値
+    ///
+    /// 削除したカテゴリの数
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数のカテゴリIDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn delete(&self, id: &str) -> UsecaseResult<u32> {
+        let id = convert_to_category_id(id)?;
+
+        self.repository.delete(id).await.map_err(|e| e.into())
+    }
+}
+
+/// 文字列をカテゴリIDに変換する。
+///
+/// # 引数
+///
+/// * `id` - カテゴリIDを表す文字列
+///
+/// # 戻り値
+///
+/// カテゴリID
+///
+/// # エラー
+///
+/// * `UsecaseError::Validation` - 引数のカテゴリIDがUUIDv4形式でない場合
+pub fn convert_to_category_id(id: &str) -> UsecaseResult<CategoryId> {
+    id.try_into().map_err(|_| {
+        UsecaseError::Validation("UUIDv4形式の文字列でカテゴリIDを指定してください。".into())
+    })
+}
@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+
+use super::vegetable::convert_to_vegetable_id;
+use crate::UsecaseResult;
+use domain::models::inventory::Inventory;
+use domain::models::primitives::Quantity;
+use domain::repositories::inventory::InventoryRepository;
+
+/// 入荷または廃棄する数量
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryQuantityInput {
+    /// 数量
+    pub quantity: u32,
+}
+
+/// 在庫ユースケースインタラクター
+#[async_trait]
+pub trait InventoryInteractor: Clone {
+    /// 野菜IDで指定した野菜の在庫を検索する。
+    async fn find_by_vegetable_id(&self, vegetable_id: &str) -> UsecaseResult<Option<Inventory>>;
+
+    /// 在庫を管理しているすべての野菜の在庫を検索する。
+    async fn find_all(&self) -> UsecaseResult<Vec<Inventory>>;
+
+    /// 野菜を入荷する。
+    async fn receive(
+        &self,
+        vegetable_id: &str,
+        input: InventoryQuantityInput,
+    ) -> UsecaseResult<Option<Inventory>>;
+
+    /// 傷んだ野菜などを廃棄する。
+    async fn dispose(
+        &self,
+        vegetable_id: &str,
+        input: InventoryQuantityInput,
+    ) -> UsecaseResult<Option<Inventory>>;
+
+    /// 野菜IDで指定した野菜の在庫の管理を終了する。
+    async fn delete(&self, vegetable_id: &str) -> UsecaseResult<u32>;
+}
+
+/// 在庫ユースケースインタラクターの実装
+///
+/// 在庫リポジトリを通じて在庫を永続化するため、在庫リポジトリを実装した任意の永続化層で
+/// 使用できる。
+#[derive(Clone, Debug)]
+pub struct InventoryInteractorImpl<R>
+where
+    R: InventoryRepository,
+{
+    /// 在庫リポジトリ
+    repository: R,
+}
+
+impl<R> InventoryInteractorImpl<R>
+where
+    R: InventoryRepository,
+{
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `repository` - 在庫リポジトリ
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫インタラクター
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> InventoryInteractor for InventoryInteractorImpl<R>
+where
+    R: InventoryRepository + Clone + Send + Sync,
+{
+    /// 野菜IDで指定した野菜の在庫を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫（在庫を管理していない野菜の場合は`None`）
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_by_vegetable_id(&self, vegetable_id: &str) -> UsecaseResult<Option<Inventory>> {
+        let vegetable_id = convert_to_vegetable_id(vegetable_id)?;
+
+        self.repository
+            .find_by_vegetable_id(vegetable_id)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// 在庫を管理しているすべての野菜の在庫を検索する。
+    ///
+    /// # 戻り値
+    ///
+    /// 在庫を格納したベクタ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_all(&self) -> UsecaseResult<Vec<Inventory>> {
+        self.repository.find_all().await.map_err(|e| e.into())
+    }
+
+    /// 野菜を入荷する。
+    ///
+    /// 在庫を管理していない野菜の場合は、在庫の管理を開始する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    /// * `input` - 入荷した数量
+    ///
+    /// # 戻り値
+    ///
+    /// 入荷した数量を加えた在庫（野菜が存在しない場合は`None`）
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 野菜IDがUUIDv4形式でない場合、数量が1未満の場合、
+    ///   または在庫数が最大値を超える場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn receive(
+        &self,
+        vegetable_id: &str,
+        input: InventoryQuantityInput,
+    ) -> UsecaseResult<Option<Inventory>> {
+        let vegetable_id = convert_to_vegetable_id(vegetable_id)?;
+        let quantity = Quantity::try_from(input.quantity)?;
+
+        self.repository
+            .receive(vegetable_id, quantity)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// 傷んだ野菜などを廃棄する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    /// * `input` - 廃棄する数量
+    ///
+    /// # 戻り値
+    ///
+    /// 廃棄した数量を減じた在庫（在庫を管理していない野菜の場合は`None`）
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 野菜IDがUUIDv4形式でない場合、または数量が1未満の場合
+    /// * `UsecaseError::DomainRule` - 廃棄する数量が在庫数を超える場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn dispose(
+        &self,
+        vegetable_id: &str,
+        input: InventoryQuantityInput,
+    ) -> UsecaseResult<Option<Inventory>> {
+        let vegetable_id = convert_to_vegetable_id(vegetable_id)?;
+        let quantity = Quantity::try_from(input.quantity)?;
+
+        self.repository
+            .dispose(vegetable_id, quantity)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// 野菜IDで指定した野菜の在庫の管理を終了する。
+    ///
+    /// 在庫の管理を終了した野菜は、在庫が無限にあるものとして販売する。
+    ///
+    /// # 引数
+    ///
+    /// * `vegetable_id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 削除した在庫の数
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn delete(&self, vegetable_id: &str) -> UsecaseResult<u32> {
+        let vegetable_id = convert_to_vegetable_id(vegetable_id)?;
+
+        self.repository
+            .delete(vegetable_id)
+            .await
+            .map_err(|e| e.into())
+    }
+}
@@ -1,13 +1,137 @@
+pub mod audit;
+pub mod category;
+pub mod inventory;
+pub mod report;
+pub mod sale;
 pub mod vegetable;
 
+use self::audit::AuditLogInteractor;
+use self::category::CategoryInteractor;
+use self::inventory::InventoryInteractor;
+use self::report::ReportInteractor;
+use self::sale::SaleInteractor;
 use self::vegetable::VegetableInteractor;
+use crate::UsecaseResult;
+use domain::models::primitives::Actor;
 
 /// ユースケースインタラクターコンテナ
+///
+/// ルーターとハンドラ関数は、ユースケースインタラクターコンテナの型だけを型引数に取り、
+/// コンテナが提供するユースケースインタラクターを関連型で参照する。
+pub trait InteractorContainer: Clone + 'static {
+    /// 野菜ユースケースインタラクターの型
+    type Vegetable: VegetableInteractor;
+    /// 販売ユースケースインタラクターの型
+    type Sale: SaleInteractor;
+    /// 売上集計ユースケースインタラクターの型
+    type Report: ReportInteractor;
+    /// 在庫ユースケースインタラクターの型
+    type Inventory: InventoryInteractor;
+    /// カテゴリユースケースインタラクターの型
+    type Category: CategoryInteractor;
+    /// 監査ログユースケースインタラクターの型
+    type Audit: AuditLogInteractor;
+
+    /// 野菜ユースケースインタラクターを返す。
+    fn vegetable(&self) -> &Self::Vegetable;
+
+    /// 販売ユースケースインタラクターを返す。
+    fn sale(&self) -> &Self::Sale;
+
+    /// 売上集計ユースケースインタラクターを返す。
+    fn report(&self) -> &Self::Report;
+
+    /// 在庫ユースケースインタラクターを返す。
+    fn inventory(&self) -> &Self::Inventory;
+
+    /// カテゴリユースケースインタラクターを返す。
+    fn category(&self) -> &Self::Category;
+
+    /// 監査ログユースケースインタラクターを返す。
+    fn audit(&self) -> &Self::Audit;
+}
+
+/// ユースケースインタラクターを保持するユースケースインタラクターコンテナ
 #[derive(Clone, Debug)]
-pub struct UsecaseInteractorContainer<VI>
+pub struct UsecaseInteractorContainer<VI, SI, RI, II, CI, AI>
 where
     VI: VegetableInteractor,
+    SI: SaleInteractor,
+    RI: ReportInteractor,
+    II: InventoryInteractor,
+    CI: CategoryInteractor,
+    AI: AuditLogInteractor,
 {
     /// 野菜ユースケースインタラクター
     pub vegetable: VI,
+    /// 販売ユースケースインタラクター
+    pub sale: SI,
+    /// 売上集計ユースケースインタラクター
+    pub report: RI,
+    /// 在庫ユースケースインタラクター
+    pub inventory: II,
+    /// カテゴリユースケースインタラクター
+    pub category: CI,
+    /// 監査ログユースケースインタラクター
+    pub audit: AI,
+}
+
+impl<VI, SI, RI, II, CI, AI> InteractorContainer
+    for UsecaseInteractorContainer<VI, SI, RI, II, CI, AI>
+where
+    VI: VegetableInteractor + 'static,
+    SI: SaleInteractor + 'static,
+    RI: ReportInteractor + 'static,
+    II: InventoryInteractor + 'static,
+    CI: CategoryInteractor + 'static,
+    AI: AuditLogInteractor + 'static,
+{
+    type Vegetable = VI;
+    type Sale = SI;
+    type Report = RI;
+    type Inventory = II;
+    type Category = CI;
+    type Audit = AI;
+
+    fn vegetable(&self) -> &Self::Vegetable {
+        &self.vegetable
+    }
+
+    fn sale(&self) -> &Self::Sale {
+        &self.sale
+    }
+
+    fn report(&self) -> &Self::Report {
+        &self.report
+    }
+
+    fn inventory(&self) -> &Self::Inventory {
+        &self.inventory
+    }
+
+    fn category(&self) -> &Self::Category {
+        &self.category
+    }
+
+    fn audit(&self) -> &Self::Audit {
+        &self.audit
+    }
+}
+
+/// 文字列を利用者に変換する。
+///
+/// # 引数
+///
+/// * `actor` - 利用者を表す文字列
+///
+/// # 戻り値
+///
+/// 利用者
+///
+/// # エラー
+///
+/// * `UsecaseError::Validation` - 利用者が空文字列の場合、または利用者の文字数が最大文字数を
+///   超える場合
+pub fn convert_to_actor(actor: &str) -> UsecaseResult<Actor> {
+    Actor::try_from(actor).map_err(|e| e.into())
 }
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use time::macros::format_description;
+use time::{Date, Duration, UtcOffset};
+
+use crate::{UsecaseError, UsecaseResult};
+use domain::repositories::report::{ReportRepository, VegetableSales};
+
+time::serde::format_description!(date_format, Date, "[year]-[month]-[day]");
+
+/// 日次売上集計の条件
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailySalesInput {
+    /// 集計する日付（YYYY-MM-DD形式）
+    pub date: String,
+}
+
+/// 野菜ごとの売上
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VegetableSalesSummary {
+    /// 野菜ID
+    pub vegetable_id: String,
+    /// 野菜名
+    pub vegetable_name: String,
+    /// 個、束または袋で販売した数量（量り売りで販売した野菜は含まない）
+    pub quantity: u64,
+    /// 量り売りで販売した重さ（グラム、量り売りで販売していない場合は`None`）
+    pub weight: Option<u64>,
+    /// 売上金額（販売明細の小計の合計）
+    pub revenue: u64,
+}
+
+impl From<VegetableSales> for VegetableSalesSummary {
+    fn from(value: VegetableSales) -> Self {
+        Self {
+            vegetable_id: value.vegetable_id.value().to_string(),
+            vegetable_name: value.vegetable_name.value().to_string(),
+            quantity: value.quantity,
+            weight: value.weight,
+            revenue: value.revenue,
+        }
+    }
+}
+
+/// 日次売上集計
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailySalesSummary {
+    /// 集計した日付
+    #[serde(with = "date_format")]
+    pub date: Date,
+    /// 販売数
+    pub number_of_sales: u64,
+    /// 売上金額（販売明細の小計の合計）
+    pub total_revenue: u64,
+    /// 野菜ごとの売上（売上金額の降順）
+    pub vegetables: Vec<VegetableSalesSummary>,
+    /// 売上金額が多い上位の野菜
+    pub top_sellers: Vec<VegetableSalesSummary>,
+}
+
+/// 売上集計ユースケースインタラクター
+#[async_trait]
+pub trait ReportInteractor: Clone {
+    /// 指定した日の売上を集計する。
+    async fn daily_sales(&self, input: DailySalesInput) -> UsecaseResult<DailySalesSummary>;
+}
+
+/// 売上集計ユースケースインタラクターの実装
+///
+/// 売上集計リポジトリを通じて売上を集計するため、売上集計リポジトリを実装した任意の
+/// 永続化層で使用できる。
+#[derive(Clone, Debug)]
+pub struct ReportInteractorImpl<R>
+where
+    R: ReportRepository,
+{
+    /// 売上集計リポジトリ
+    repository: R,
+    /// 集計する日の境界を決めるUTCからのオフセット
+    offset: UtcOffset,
+}
+
+impl<R> ReportInteractorImpl<R>
+where
+    R: ReportRepository,
+{
+    /// 日次売上集計で返却する、売上金額が多い上位の野菜の数
+    const TOP_SELLERS: usize = 3;
+
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `repository` - 売上集計リポジトリ
+    /// * `offset` - 集計する日の境界を決めるUTCからのオフセット
+    ///
+    /// # 戻り値
+    ///
+    /// 売上集計インタラクター
+    pub fn new(repository: R, offset: UtcOffset) -> Self {
+        Self { repository, offset }
+    }
+}
+
+#[async_trait]
+impl<R> ReportInteractor for ReportInteractorImpl<R>
+where
+    R: ReportRepository + Clone + Send + Sync,
+{
+    /// 指定した日の売上を集計する。
+    ///
+    /// # 引数
+    ///
+    /// * `input` - 日次売上集計の条件
+    ///
+    /// # 戻り値
+    ///
+    /// 日次売上集計
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 日付がYYYY-MM-DD形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn daily_sales(&self, input: DailySalesInput) -> UsecaseResult<DailySalesSummary> {
+        let date = convert_to_date(&input.date)?;
+        let from = date.midnight().assume_offset(self.offset);
+        let to = from + Duration::days(1);
+        let summary = self.repository.summarize_sales(from, to).await?;
+        let vegetables: Vec<VegetableSalesSummary> =
+            summary.vegetables.into_iter().map(|v| v.into()).collect();
+        let top_sellers = vegetables.iter().take(Self::TOP_SELLERS).cloned().collect();
+
+        Ok(DailySalesSummary {
+            date,
+            number_of_sales: summary.number_of_sales,
+            total_revenue: summary.total_revenue,
+            vegetables,
+            top_sellers,
+        })
+    }
+}
+
+/// 文字列を日付に変換する。
+///
+/// # 引数
+///
+/// * `date` - YYYY-MM-DD形式の日付を表す文字列
+///
+/// # 戻り値
+///
+/// 日付
+///
+/// # エラー
+///
+/// * `UsecaseError::Validation` - 引数の日付がYYYY-MM-DD形式でない場合
+fn convert_to_date(date: &str) -> UsecaseResult<Date> {
+    Date::parse(date, format_description!("[year]-[month]-[day]")).map_err(|_| {
+        UsecaseError::Validation("YYYY-MM-DD形式の文字列で日付を指定してください。".into())
+    })
+}
@@ -0,0 +1,321 @@
+use async_trait::async_trait;
+use time::{OffsetDateTime, UtcOffset};
+
+use super::convert_to_actor;
+use super::vegetable::convert_to_vegetable_id;
+use crate::{UsecaseError, UsecaseResult};
+use domain::models::primitives::{Quantity, Weight};
+use domain::models::sales::{ReturnedSale, Sale, SaleDetail, SaleId};
+use domain::models::tax::TaxPolicy;
+use domain::repositories::sale::SaleRepository;
+use domain::repositories::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+use domain::repositories::vegetable::VegetableRepository;
+
+/// 購入する野菜
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseItemInput {
+    /// 野菜ID
+    pub vegetable_id: String,
+    /// 数量
+    pub quantity: u32,
+    /// 量り売りする野菜の重さ（野菜1つあたりのグラム）
+    pub weight: Option<u32>,
+}
+
+/// 購入
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseInput {
+    /// 購入する野菜
+    pub items: Vec<PurchaseItemInput>,
+}
+
+/// 返品する野菜
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnItemInput {
+    /// 野菜ID
+    pub vegetable_id: String,
+    /// 返品する数量
+    pub quantity: u32,
+}
+
+/// 販売ユースケースインタラクター
+#[async_trait]
+pub trait SaleInteractor: Clone {
+    /// 販売IDで指定された販売を検索する。
+    async fn find_by_id(&self, id: &str) -> UsecaseResult<Option<Sale>>;
+
+    /// すべての販売を検索する。
+    async fn find_all(&self) -> UsecaseResult<Vec<Sale>>;
+
+    /// 代金を支払い野菜を購入する。
+    async fn purchase(&self, input: PurchaseInput, actor: &str) -> UsecaseResult<Sale>;
+
+    /// 購入した野菜のうち、いずれかの野菜を返品する。
+    async fn return_item(
+        &self,
+        id: &str,
+        input: ReturnItemInput,
+        actor: &str,
+    ) -> UsecaseResult<Option<ReturnedSale>>;
+
+    /// 購入した野菜をすべて返品して、購入をキャンセルする。
+    async fn cancel(&self, id: &str, actor: &str) -> UsecaseResult<u32>;
+}
+
+/// 販売ユースケースインタラクターの実装
+///
+/// ユニットオブワークが提供する野菜リポジトリと販売リポジトリを通じて販売を永続化するため、
+/// ユニットオブワークを実装した任意の永続化層で使用できる。ユースケースごとに
+/// ユニットオブワークを開始して、ユースケースが行った変更をまとめてコミットする。
+#[derive(Clone, Debug)]
+pub struct SaleInteractorImpl<F>
+where
+    F: UnitOfWorkFactory,
+{
+    /// ユニットオブワークファクトリ
+    unit_of_work: F,
+    /// 販売に適用する消費税の計算方法
+    tax_policy: TaxPolicy,
+    /// 野菜を販売する月の境界を決めるUTCからのオフセット
+    offset: UtcOffset,
+}
+
+impl<F> SaleInteractorImpl<F>
+where
+    F: UnitOfWorkFactory,
+{
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `unit_of_work` - ユニットオブワークファクトリ
+    /// * `tax_policy` - 販売に適用する消費税の計算方法
+    /// * `offset` - 野菜を販売する月の境界を決めるUTCからのオフセット
+    ///
+    /// # 戻り値
+    ///
+    /// 販売インタラクター
+    pub fn new(unit_of_work: F, tax_policy: TaxPolicy, offset: UtcOffset) -> Self {
+        Self {
+            unit_of_work,
+            tax_policy,
+            offset,
+        }
+    }
+}
+
+#[async_trait]
+impl<F> SaleInteractor for SaleInteractorImpl<F>
+where
+    F: UnitOfWorkFactory + Clone + Send + Sync,
+{
+    /// 販売IDで指定した販売を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売ID
+    ///
+    /// # 戻り値
+    ///
+    /// 販売
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の販売IDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_by_id(&self, id: &str) -> UsecaseResult<Option<Sale>> {
+        let id = convert_to_sale_id(id)?;
+        let uow = self.unit_of_work.begin().await?;
+        let sale = uow.sale().find_by_id(id).await?;
+        uow.commit().await?;
+
+        Ok(sale)
+    }
+
+    /// すべての販売を検索する。
+    ///
+    /// # 戻り値
+    ///
+    /// 販売を格納したベクタ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_all(&self) -> UsecaseResult<Vec<Sale>> {
+        let uow = self.unit_of_work.begin().await?;
+        let sales = uow.sale().find_all().await?;
+        uow.commit().await?;
+
+        Ok(sales)
+    }
+
+    /// 代金を支払い野菜を購入する。
+    ///
+    /// 購入する野菜の現在の単価、単位及び消費税率で販売明細を作成して、インタラクターに
+    /// 設定された消費税の計算方法で消費税を計算した販売を登録する。量り売りする野菜は、
+    /// 野菜1つあたりの重さを指定する。販売を終了した野菜と、今月販売していない野菜は
+    /// 購入できない。野菜の検索と販売の登録は、1つのユニットオブワークで行い、購入する
+    /// 野菜は販売を登録するまで変更されないように共有ロックして検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `input` - 購入する野菜と数量
+    /// * `actor` - 販売を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した販売
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 野菜IDがUUIDv4形式でない場合、野菜が存在しない場合、
+    ///   数量が1未満の場合、量り売りする野菜の重さが指定されていない場合、量り売り以外の
+    ///   野菜に重さが指定されている場合、合計販売金額が扱える範囲を超える場合、または
+    ///   利用者が正しくない場合
+    /// * `UsecaseError::DomainRule` - 購入する野菜が指定されていない場合、同じ野菜が複数
+    ///   指定された場合、または販売を終了した野菜や今月販売していない野菜が指定された場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn purchase(&self, input: PurchaseInput, actor: &str) -> UsecaseResult<Sale> {
+        let actor = convert_to_actor(actor)?;
+        let sold_at = OffsetDateTime::now_utc();
+        let month = sold_at.to_offset(self.offset).month();
+        let uow = self.unit_of_work.begin().await?;
+        let mut sale_details = Vec::with_capacity(input.items.len());
+        for item in input.items {
+            let id = convert_to_vegetable_id(&item.vegetable_id)?;
+            let vegetable = uow
+                .vegetable()
+                .find_by_id_for_share(id)
+                .await?
+                .ok_or_else(|| {
+                    UsecaseError::Validation(
+                        format!("野菜が見つかりません。(野菜ID: {})", item.vegetable_id).into(),
+                    )
+                })?;
+            vegetable.ensure_available(month)?;
+            let quantity = Quantity::try_from(item.quantity)?;
+            let weight = item.weight.map(Weight::try_from).transpose()?;
+            let unit_price = vegetable.unit_price();
+            let unit = vegetable.unit();
+            let tax_rate = vegetable.tax_rate();
+            sale_details.push(SaleDetail::new(
+                vegetable, unit_price, unit, quantity, weight, tax_rate,
+            )?);
+        }
+        let sale = Sale::new(sold_at, sale_details, self.tax_policy)?;
+        let sale = uow.sale().register(sale, &actor).await?;
+        uow.commit().await?;
+
+        Ok(sale)
+    }
+
+    /// 購入した野菜のうち、いずれかの野菜を返品する。
+    ///
+    /// 返品した結果、販売明細が残っている場合は販売明細を置き換え、販売明細が残っていない
+    /// 場合は販売を削除する。販売の検索と販売の更新は、1つのユニットオブワークで行う。
+    /// 販売は排他ロックして検索するため、同じ販売への返品を並行して受け付けた場合でも、
+    /// 後の返品は先の返品を反映した販売に対して行われる。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売ID
+    /// * `input` - 返品する野菜と数量
+    /// * `actor` - 返品を受け付ける利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 返品した結果
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 販売IDまたは野菜IDがUUIDv4形式でない場合、
+    ///   数量が1未満の場合、または利用者が正しくない場合
+    /// * `UsecaseError::DomainRule` - 返品する野菜を購入していない場合、または返品する数量が
+    ///   購入した数量を超える場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn return_item(
+        &self,
+        id: &str,
+        input: ReturnItemInput,
+        actor: &str,
+    ) -> UsecaseResult<Option<ReturnedSale>> {
+        let id = convert_to_sale_id(id)?;
+        let vegetable_id = convert_to_vegetable_id(&input.vegetable_id)?;
+        let quantity = Quantity::try_from(input.quantity)?;
+        let actor = convert_to_actor(actor)?;
+        let uow = self.unit_of_work.begin().await?;
+        let sale = match uow.sale().find_by_id_for_update(id).await? {
+            Some(sale) => sale,
+            None => return Ok(None),
+        };
+        let returned = match sale.return_item(vegetable_id, quantity)? {
+            ReturnedSale::Remaining(sale) => uow
+                .sale()
+                .replace_details(sale, &actor)
+                .await?
+                .map(ReturnedSale::Remaining),
+            ReturnedSale::FullyReturned(sale) => match uow.sale().delete(&sale, &actor).await? {
+                0 => None,
+                _ => Some(ReturnedSale::FullyReturned(sale)),
+            },
+        };
+        uow.commit().await?;
+
+        Ok(returned)
+    }
+
+    /// 購入した野菜をすべて返品して、購入をキャンセルする。
+    ///
+    /// 販売を削除する。販売明細は、外部キー制約により販売と一緒に削除される。販売の検索と
+    /// 販売の削除は、1つのユニットオブワークで行い、販売は排他ロックして検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 販売ID
+    /// * `actor` - キャンセルを受け付ける利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 削除した販売の数
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の販売IDがUUIDv4形式でない場合、または利用者が
+    ///   正しくない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn cancel(&self, id: &str, actor: &str) -> UsecaseResult<u32> {
+        let id = convert_to_sale_id(id)?;
+        let actor = convert_to_actor(actor)?;
+        let uow = self.unit_of_work.begin().await?;
+        let sale = match uow.sale().find_by_id_for_update(id).await? {
+            Some(sale) => sale.cancel(),
+            None => return Ok(0),
+        };
+        let deleted = uow.sale().delete(&sale, &actor).await?;
+        uow.commit().await?;
+
+        Ok(deleted)
+    }
+}
+
+/// 文字列を販売IDに変換する。
+///
+/// # 引数
+///
+/// * `id` - 販売IDを表す文字列
+///
+/// # 戻り値
+///
+/// 販売ID
+///
+/// # エラー
+///
+/// * `UsecaseError::Validation` - 引数の販売IDがUUIDv4形式でない場合
+fn convert_to_sale_id(id: &str) -> UsecaseResult<SaleId> {
+    id.try_into().map_err(|_| {
+        UsecaseError::Validation("UUIDv4形式の文字列で販売IDを指定してください。".into())
+    })
+}
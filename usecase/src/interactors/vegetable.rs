@@ -1,8 +1,19 @@
 use async_trait::async_trait;
+use time::{OffsetDateTime, UtcOffset};
 
-use crate::UsecaseResult;
-use domain::models::vegetable::Vegetable;
-use domain::repositories::vegetable::{PartialVegetable, UpsertVegetable};
+use super::category::convert_to_category_id;
+use super::convert_to_actor;
+use crate::{UsecaseError, UsecaseResult};
+use domain::models::availability::AvailableMonths;
+use domain::models::primitives::{Price, Tag, VegetableName, Weight};
+use domain::models::tax::TaxRate;
+use domain::models::unit::UnitOfMeasure;
+use domain::models::vegetable::{Vegetable, VegetableId, VegetablePrice};
+use domain::repositories::vegetable::{
+    PartialVegetable, SortOrder, UpsertVegetable, VegetablePage, VegetableQuery,
+    VegetableRepository, VegetableSortKey,
+};
+use domain::{DomainError, DomainResult};
 
 /// 登録または更新する野菜
 #[derive(serde::Deserialize)]
@@ -12,14 +23,50 @@ pub struct UpsertVegetableInput {
     pub name: String,
     /// 野菜の単価
     pub unit_price: u32,
+    /// 野菜の消費税率（`reduced`または`standard`、省略した場合は`reduced`）
+    pub tax_rate: Option<String>,
+    /// 野菜を販売する単位（`piece`、`bundle`、`bag`または`weight`、省略した場合は`piece`）
+    pub unit: Option<String>,
+    /// 量り売りする野菜の単価の基準となる重さ（グラム）
+    pub unit_grams: Option<u32>,
+    /// 野菜を分類するカテゴリのカテゴリID（省略した場合はカテゴリに分類しない）
+    pub category_id: Option<String>,
+    /// タグ（省略した場合はタグを付けない）
+    pub tags: Option<Vec<String>>,
+    /// 販売する月（1から12までの整数、省略した場合は通年）
+    pub available_months: Option<Vec<u8>>,
+    /// 販売中かどうか（省略した場合は販売中）
+    pub active: Option<bool>,
 }
 
-impl From<UpsertVegetableInput> for UpsertVegetable {
-    fn from(value: UpsertVegetableInput) -> Self {
-        Self {
-            name: value.name,
-            unit_price: value.unit_price.into(),
-        }
+impl TryFrom<UpsertVegetableInput> for UpsertVegetable {
+    type Error = UsecaseError;
+
+    fn try_from(value: UpsertVegetableInput) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name.try_into()?,
+            unit_price: value.unit_price.try_into()?,
+            tax_rate: value
+                .tax_rate
+                .as_deref()
+                .map(TaxRate::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+            unit: convert_to_unit(value.unit.as_deref(), value.unit_grams)?.unwrap_or_default(),
+            category_id: value
+                .category_id
+                .as_deref()
+                .map(convert_to_category_id)
+                .transpose()?,
+            tags: convert_to_tags(value.tags.unwrap_or_default())?,
+            available_months: value
+                .available_months
+                .as_deref()
+                .map(AvailableMonths::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+            active: value.active.unwrap_or(true),
+        })
     }
 }
 
@@ -31,14 +78,233 @@ pub struct PartialVegetableInput {
     pub name: Option<String>,
     /// 単価
     pub unit_price: Option<u32>,
+    /// 消費税率（`reduced`または`standard`）
+    pub tax_rate: Option<String>,
+    /// 販売する単位（`piece`、`bundle`、`bag`または`weight`）
+    pub unit: Option<String>,
+    /// 量り売りする野菜の単価の基準となる重さ（グラム）
+    pub unit_grams: Option<u32>,
+    /// 野菜を分類するカテゴリのカテゴリID（`null`を指定した場合はカテゴリへの分類を解除する）
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub category_id: Option<Option<String>>,
+    /// タグ（指定した場合はすべてのタグを置き換える）
+    pub tags: Option<Vec<String>>,
+    /// 販売する月（1から12までの整数）
+    pub available_months: Option<Vec<u8>>,
+    /// 販売中かどうか
+    pub active: Option<bool>,
+}
+
+impl TryFrom<PartialVegetableInput> for PartialVegetable {
+    type Error = UsecaseError;
+
+    fn try_from(value: PartialVegetableInput) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name.map(|value| value.try_into()).transpose()?,
+            unit_price: value.unit_price.map(|value| value.try_into()).transpose()?,
+            tax_rate: value
+                .tax_rate
+                .as_deref()
+                .map(TaxRate::try_from)
+                .transpose()?,
+            unit: convert_to_unit(value.unit.as_deref(), value.unit_grams)?,
+            category_id: value
+                .category_id
+                .map(|id| id.as_deref().map(convert_to_category_id).transpose())
+                .transpose()?,
+            tags: value.tags.map(convert_to_tags).transpose()?,
+            available_months: value
+                .available_months
+                .as_deref()
+                .map(AvailableMonths::try_from)
+                .transpose()?,
+            active: value.active,
+        })
+    }
+}
+
+/// 指定されたフィールドの値を`Some`で包んでデシリアライズする。
+///
+/// フィールドを省略した場合は`None`、`null`を指定した場合は`Some(None)`とするため、
+/// `#[serde(default)]`とともに使用する。
+fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// 文字列をタグに変換して、重複を取り除いてタグの順に並べる。
+///
+/// # 引数
+///
+/// * `tags` - タグを表す文字列のベクタ
+///
+/// # 戻り値
+///
+/// タグのベクタ
+///
+/// # エラー
+///
+/// * `DomainError::Validation` - タグが正しくない場合、またはタグの数が最大数を超える場合
+fn convert_to_tags(tags: Vec<String>) -> DomainResult<Vec<Tag>> {
+    let tags = tags
+        .into_iter()
+        .map(Tag::try_from)
+        .collect::<DomainResult<Vec<_>>>()?;
+
+    Vegetable::arrange_tags(tags)
+}
+
+/// 単位を表すコードと単価の基準となる重さを単位に変換する。
+///
+/// # 引数
+///
+/// * `unit` - 単位を表すコード
+/// * `unit_grams` - 量り売りする野菜の単価の基準となる重さ（グラム）
+///
+/// # 戻り値
+///
+/// 単位（単位を表すコードが指定されていない場合は`None`）
+///
+/// # エラー
+///
+/// * `DomainError::Validation` - 単位が正しくない場合、または単位を指定せずに重さを
+///   指定した場合
+fn convert_to_unit(
+    unit: Option<&str>,
+    unit_grams: Option<u32>,
+) -> DomainResult<Option<UnitOfMeasure>> {
+    let unit_grams = unit_grams.map(Weight::try_from).transpose()?;
+    match (unit, unit_grams) {
+        (Some(code), _) => Ok(Some(UnitOfMeasure::from_code(code, unit_grams)?)),
+        (None, Some(_)) => Err(DomainError::Validation(
+            "単価の基準となる重さを指定する場合は、単位を指定してください。".into(),
+        )),
+        (None, None) => Ok(None),
+    }
+}
+
+/// 登録する野菜の価格
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VegetablePriceInput {
+    /// 単価
+    pub unit_price: u32,
+    /// 適用開始日時（RFC3339形式、省略した場合は現在）
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub effective_from: Option<OffsetDateTime>,
+}
+
+impl TryFrom<VegetablePriceInput> for VegetablePrice {
+    type Error = DomainError;
+
+    fn try_from(value: VegetablePriceInput) -> Result<Self, Self::Error> {
+        Self::schedule(value.unit_price.try_into()?, value.effective_from)
+    }
+}
+
+/// 野菜の削除方法
+#[derive(Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VegetableDeleteInput {
+    /// 物理削除するかどうか（省略した場合は論理削除）
+    pub permanent: Option<bool>,
+}
+
+/// 野菜の検索条件
+#[derive(Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VegetableListInput {
+    /// 取得するページ（1から始まる）
+    pub page: Option<u32>,
+    /// 1ページに含める野菜の数
+    pub limit: Option<u32>,
+    /// 野菜名に含まれる文字列
+    pub name: Option<String>,
+    /// 単価の下限
+    pub min_unit_price: Option<u32>,
+    /// 単価の上限
+    pub max_unit_price: Option<u32>,
+    /// 野菜を分類するカテゴリのカテゴリID
+    pub category: Option<String>,
+    /// 野菜に付けられたタグ
+    pub tag: Option<String>,
+    /// 販売を終了した野菜と、旬の時期ではない野菜を含めるかどうか（省略した場合は含めない）
+    pub include_unavailable: Option<bool>,
+    /// 野菜を並び替える項目（`name`、`unitPrice`または`createdAt`）
+    pub sort: Option<String>,
+    /// 並び順（`asc`または`desc`）
+    pub order: Option<String>,
 }
 
-impl From<PartialVegetableInput> for PartialVegetable {
-    fn from(value: PartialVegetableInput) -> Self {
-        Self {
-            name: value.name,
-            unit_price: value.unit_price.map(|value| value.into()),
+impl TryFrom<VegetableListInput> for VegetableQuery {
+    type Error = UsecaseError;
+
+    fn try_from(value: VegetableListInput) -> Result<Self, Self::Error> {
+        let page = value.page.unwrap_or(1);
+        if page < 1 {
+            return Err(UsecaseError::Validation(
+                "ページは1以上で指定してください。".into(),
+            ));
         }
+        let limit = value.limit.unwrap_or(Self::DEFAULT_LIMIT);
+        if !(1..=Self::MAX_LIMIT).contains(&limit) {
+            return Err(UsecaseError::Validation(
+                format!(
+                    "1ページに含める野菜の数は1以上{}以下で指定してください。",
+                    Self::MAX_LIMIT
+                )
+                .into(),
+            ));
+        }
+        if let (Some(min), Some(max)) = (value.min_unit_price, value.max_unit_price) {
+            if max < min {
+                return Err(UsecaseError::Validation(
+                    "単価の上限は単価の下限以上で指定してください。".into(),
+                ));
+            }
+        }
+        let sort_key = match value.sort.as_deref() {
+            None | Some("name") => VegetableSortKey::Name,
+            Some("unitPrice") => VegetableSortKey::UnitPrice,
+            Some("createdAt") => VegetableSortKey::CreatedAt,
+            Some(_) => {
+                return Err(UsecaseError::Validation(
+                    "並び替える項目はname、unitPriceまたはcreatedAtで指定してください。".into(),
+                ))
+            }
+        };
+        let sort_order = match value.order.as_deref() {
+            None | Some("asc") => SortOrder::Asc,
+            Some("desc") => SortOrder::Desc,
+            Some(_) => {
+                return Err(UsecaseError::Validation(
+                    "並び順はascまたはdescで指定してください。".into(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            page,
+            limit,
+            name: value
+                .name
+                .map(|name| VegetableName::normalize(&name))
+                .filter(|name| !name.is_empty()),
+            min_unit_price: value.min_unit_price.map(Price::try_from).transpose()?,
+            max_unit_price: value.max_unit_price.map(Price::try_from).transpose()?,
+            category_id: value
+                .category
+                .as_deref()
+                .map(convert_to_category_id)
+                .transpose()?,
+            tag: value.tag.map(Tag::try_from).transpose()?,
+            available_in: None,
+            sort_key,
+            sort_order,
+        })
     }
 }
 
@@ -48,26 +314,382 @@ pub trait VegetableInteractor: Clone {
     /// 野菜IDで指定された野菜を検索する。
     async fn find_by_id(&self, id: &str) -> UsecaseResult<Option<Vegetable>>;
 
-    /// すべての野菜を検索する。
-    async fn find_all(&self) -> UsecaseResult<Vec<Vegetable>>;
+    /// 検索条件に一致する野菜を検索する。
+    async fn find_all(&self, input: VegetableListInput) -> UsecaseResult<VegetablePage>;
 
     /// 野菜を登録する。
-    async fn register(&self, input: UpsertVegetableInput) -> UsecaseResult<Vegetable>;
+    async fn register(&self, input: UpsertVegetableInput, actor: &str) -> UsecaseResult<Vegetable>;
 
     /// 野菜を更新する。
     async fn update(
         &self,
         id: &str,
+        version: Option<u32>,
         vegetable: UpsertVegetableInput,
+        actor: &str,
     ) -> UsecaseResult<Option<Vegetable>>;
 
     /// 野菜を部分更新する。
     async fn partial_update(
         &self,
         id: &str,
+        version: Option<u32>,
         vegetable: PartialVegetableInput,
+        actor: &str,
     ) -> UsecaseResult<Option<Vegetable>>;
 
+    /// 野菜IDで指定した野菜の価格の履歴を検索する。
+    async fn find_prices(&self, id: &str) -> UsecaseResult<Option<Vec<VegetablePrice>>>;
+
+    /// 野菜IDで指定した野菜に、現在以降に適用する価格を登録する。
+    async fn register_price(
+        &self,
+        id: &str,
+        input: VegetablePriceInput,
+        actor: &str,
+    ) -> UsecaseResult<Option<VegetablePrice>>;
+
+    /// 野菜IDで指定した野菜を削除する。
+    async fn delete(
+        &self,
+        id: &str,
+        version: Option<u32>,
+        input: VegetableDeleteInput,
+        actor: &str,
+    ) -> UsecaseResult<u32>;
+
+    /// 野菜IDで指定した、論理削除した野菜を復元する。
+    async fn restore(&self, id: &str, actor: &str) -> UsecaseResult<Option<Vegetable>>;
+}
+
+/// 野菜ユースケースインタラクターの実装
+///
+/// 野菜リポジトリを通じて野菜を永続化するため、野菜リポジトリを実装した任意の永続化層で
+/// 使用できる。
+#[derive(Clone, Debug)]
+pub struct VegetableInteractorImpl<R>
+where
+    R: VegetableRepository,
+{
+    /// 野菜リポジトリ
+    repository: R,
+    /// 野菜を販売する月の境界を決めるUTCからのオフセット
+    offset: UtcOffset,
+}
+
+impl<R> VegetableInteractorImpl<R>
+where
+    R: VegetableRepository,
+{
+    /// コンストラクタ
+    ///
+    /// # 引数
+    ///
+    /// * `repository` - 野菜リポジトリ
+    /// * `offset` - 野菜を販売する月の境界を決めるUTCからのオフセット
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜インタラクター
+    pub fn new(repository: R, offset: UtcOffset) -> Self {
+        Self { repository, offset }
+    }
+}
+
+#[async_trait]
+impl<R> VegetableInteractor for VegetableInteractorImpl<R>
+where
+    R: VegetableRepository + Clone + Send + Sync,
+{
+    /// 野菜IDで指定した野菜を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_by_id(&self, id: &str) -> UsecaseResult<Option<Vegetable>> {
+        let id = convert_to_vegetable_id(id)?;
+        self.repository.find_by_id(id).await.map_err(|e| e.into())
+    }
+
+    /// 検索条件に一致する野菜を検索する。
+    ///
+    /// 販売を終了した野菜と旬の時期ではない野菜を含めるように指定されていない場合は、販売中で、
+    /// 今月販売する野菜のみ検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `input` - 野菜の検索条件
+    ///
+    /// # 戻り値
+    ///
+    /// 野菜の検索結果
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 検索条件が正しくない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_all(&self, input: VegetableListInput) -> UsecaseResult<VegetablePage> {
+        let include_unavailable = input.include_unavailable.unwrap_or(false);
+        let mut query: VegetableQuery = input.try_into()?;
+        if !include_unavailable {
+            query.available_in = Some(OffsetDateTime::now_utc().to_offset(self.offset).month());
+        }
+
+        self.repository.find_all(query).await.map_err(|e| e.into())
+    }
+
+    /// 野菜を登録する。
+    ///
+    /// # 引数
+    ///
+    /// * `input` - 登録する野菜
+    /// * `actor` - 野菜を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した野菜
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 野菜名などが正しくない場合、野菜を分類する
+    ///   カテゴリが存在しない場合、または利用者が正しくない場合
+    /// * `UsecaseError::DomainRule` - 同じ野菜名の野菜が既に登録されている場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn register(&self, input: UpsertVegetableInput, actor: &str) -> UsecaseResult<Vegetable> {
+        let input: UpsertVegetable = input.try_into()?;
+        let actor = convert_to_actor(actor)?;
+
+        self.repository
+            .register(input, &actor)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// 野菜を更新する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `version` - 更新する野菜の版（`None`の場合は版を確認しない）
+    /// * `input` - 更新する野菜
+    /// * `actor` - 野菜を更新する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 更新した野菜
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合、野菜名などが
+    ///   正しくない場合、野菜を分類するカテゴリが存在しない場合、または利用者が正しくない
+    ///   場合
+    /// * `UsecaseError::DomainRule` - 同じ野菜名の他の野菜が既に登録されている場合
+    /// * `UsecaseError::Conflict` - 野菜の版が一致しない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn update(
+        &self,
+        id: &str,
+        version: Option<u32>,
+        input: UpsertVegetableInput,
+        actor: &str,
+    ) -> UsecaseResult<Option<Vegetable>> {
+        let id = convert_to_vegetable_id(id)?;
+        let input: UpsertVegetable = input.try_into()?;
+        let actor = convert_to_actor(actor)?;
+
+        self.repository
+            .update(id, version, input, &actor)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// 野菜を部分更新する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `version` - 部分更新する野菜の版（`None`の場合は版を確認しない）
+    /// * `input` - 部分更新する野菜
+    /// * `actor` - 野菜を部分更新する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 部分更新した野菜
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合、野菜名などが
+    ///   正しくない場合、野菜を分類するカテゴリが存在しない場合、または利用者が正しくない
+    ///   場合
+    /// * `UsecaseError::DomainRule` - 同じ野菜名の他の野菜が既に登録されている場合
+    /// * `UsecaseError::Conflict` - 野菜の版が一致しない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn partial_update(
+        &self,
+        id: &str,
+        version: Option<u32>,
+        input: PartialVegetableInput,
+        actor: &str,
+    ) -> UsecaseResult<Option<Vegetable>> {
+        let id = convert_to_vegetable_id(id)?;
+        let input: PartialVegetable = input.try_into()?;
+        let actor = convert_to_actor(actor)?;
+
+        self.repository
+            .partial_update(id, version, input, &actor)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// 野菜IDで指定した野菜の価格の履歴を検索する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    ///
+    /// # 戻り値
+    ///
+    /// 適用開始日時の順に並べた、野菜の価格のベクタ
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn find_prices(&self, id: &str) -> UsecaseResult<Option<Vec<VegetablePrice>>> {
+        let id = convert_to_vegetable_id(id)?;
+
+        self.repository.find_prices(id).await.map_err(|e| e.into())
+    }
+
+    /// 野菜IDで指定した野菜に、現在以降に適用する価格を登録する。
+    ///
+    /// 適用開始日時が未来の価格は、適用開始日時になると自動的に野菜の単価となる。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `input` - 登録する野菜の価格
+    /// * `actor` - 野菜の価格を登録する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 登録した野菜の価格
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合、単価が
+    ///   正しくない場合、または利用者が正しくない場合
+    /// * `UsecaseError::DomainRule` - 適用開始日時が過去の場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn register_price(
+        &self,
+        id: &str,
+        input: VegetablePriceInput,
+        actor: &str,
+    ) -> UsecaseResult<Option<VegetablePrice>> {
+        let id = convert_to_vegetable_id(id)?;
+        let price: VegetablePrice = input.try_into()?;
+        let actor = convert_to_actor(actor)?;
+
+        self.repository
+            .register_price(id, price, &actor)
+            .await
+            .map_err(|e| e.into())
+    }
+
     /// 野菜IDで指定した野菜を削除する。
-    async fn delete(&self, id: &str) -> UsecaseResult<u32>;
+    ///
+    /// 物理削除するように指定されていない場合は、野菜を論理削除する。論理削除した野菜は、
+    /// 過去の販売明細から参照できる。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `version` - 削除する野菜の版（`None`の場合は版を確認しない）
+    /// * `input` - 野菜の削除方法
+    /// * `actor` - 野菜を削除する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 削除した野菜の数
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合、または利用者が
+    ///   正しくない場合
+    /// * `UsecaseError::DomainRule` - 販売で参照されている野菜を物理削除する場合
+    /// * `UsecaseError::Conflict` - 野菜の版が一致しない場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn delete(
+        &self,
+        id: &str,
+        version: Option<u32>,
+        input: VegetableDeleteInput,
+        actor: &str,
+    ) -> UsecaseResult<u32> {
+        let id = convert_to_vegetable_id(id)?;
+        let actor = convert_to_actor(actor)?;
+        let result = if input.permanent.unwrap_or(false) {
+            self.repository.purge(id, version, &actor).await
+        } else {
+            self.repository.delete(id, version, &actor).await
+        };
+
+        result.map_err(|e| e.into())
+    }
+
+    /// 野菜IDで指定した、論理削除した野菜を復元する。
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 野菜ID
+    /// * `actor` - 野菜を復元する利用者
+    ///
+    /// # 戻り値
+    ///
+    /// 復元した野菜
+    ///
+    /// # エラー
+    ///
+    /// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合、または利用者が
+    ///   正しくない場合
+    /// * `UsecaseError::DomainRule` - 同じ野菜名の他の野菜が既に登録されている場合
+    /// * `UsecaseError::Unexpected` - 予期しないエラーが発生した場合
+    async fn restore(&self, id: &str, actor: &str) -> UsecaseResult<Option<Vegetable>> {
+        let id = convert_to_vegetable_id(id)?;
+        let actor = convert_to_actor(actor)?;
+
+        self.repository
+            .restore(id, &actor)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+/// 文字列を野菜IDに変換する。
+///
+/// # 引数
+///
+/// * `id` - 野菜IDを表す文字列
+///
+/// # 戻り値
+///
+/// 野菜ID
+///
+/// # エラー
+///
+/// * `UsecaseError::Validation` - 引数の野菜IDがUUIDv4形式でない場合
+pub fn convert_to_vegetable_id(id: &str) -> UsecaseResult<VegetableId> {
+    id.try_into().map_err(|_| {
+        UsecaseError::Validation("UUIDv4形式の文字列で野菜IDを指定してください。".into())
+    })
 }
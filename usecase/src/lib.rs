@@ -1,3 +1,4 @@
+pub mod events;
 pub mod interactors;
 
 use std::borrow::Cow;
@@ -14,6 +15,10 @@ pub enum UsecaseError {
     #[error("ドメインルールエラー: {0}")]
     DomainRule(Cow<'static, str>),
 
+    /// 競合エラー
+    #[error("競合エラー: {0}")]
+    Conflict(Cow<'static, str>),
+
     /// 予期しないエラー
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
@@ -23,6 +28,8 @@ impl From<DomainError> for UsecaseError {
     fn from(value: DomainError) -> Self {
         match value {
             DomainError::Validation(message) => Self::Validation(message),
+            DomainError::DomainRule(message) => Self::DomainRule(message),
+            DomainError::Conflict(message) => Self::Conflict(message),
             DomainError::Unexpected(error) => Self::Unexpected(error),
         }
     }
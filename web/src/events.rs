@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use domain::models::events::OutboxEvent;
+use domain::repositories::outbox::OutboxRepository;
+use usecase::events::{DomainEventHandler, EventDispatcher};
+use usecase::UsecaseResult;
+
+/// ドメインイベントをログに記録するドメインイベントハンドラー
+pub struct LoggingEventHandler;
+
+#[async_trait]
+impl DomainEventHandler for LoggingEventHandler {
+    /// ドメインイベントハンドラーの名前を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// ドメインイベントハンドラーの名前
+    fn name(&self) -> &'static str {
+        "logging"
+    }
+
+    /// ドメインイベントをログに記録する。
+    ///
+    /// # 引数
+    ///
+    /// * `event` - アウトボックスに記録されたドメインイベント
+    ///
+    /// # 戻り値
+    ///
+    /// `()`
+    async fn handle(&self, event: &OutboxEvent) -> UsecaseResult<()> {
+        log::info!(
+            "ドメインイベントを受信しました。(ID: {}, 種類: {}) {:?}",
+            event.id(),
+            event.event().code(),
+            event.event()
+        );
+
+        Ok(())
+    }
+}
+
+/// 一定の間隔で、配信していないドメインイベントを配信する。
+///
+/// ドメインイベントハンドラーがパニックした場合でも配信を継続するため、配信ごとにタスクを
+/// 生成して、タスクがパニックした場合はログに記録する。
+///
+/// # 引数
+///
+/// * `dispatcher` - ドメインイベントディスパッチャー
+/// * `interval` - ドメインイベントを配信する間隔
+pub async fn run_event_dispatcher<R>(dispatcher: EventDispatcher<R>, interval: Duration)
+where
+    R: OutboxRepository + Send + Sync,
+{
+    let dispatcher = Arc::new(dispatcher);
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let task = {
+            let dispatcher = dispatcher.clone();
+            tokio::spawn(async move { dispatcher.dispatch_pending().await })
+        };
+        match task.await {
+            Ok(Ok(report)) if 0 < report.failed => log::warn!(
+                "{}件のドメインイベントの配信に失敗しました。次回の配信で再度配信します。",
+                report.failed
+            ),
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => log::error!("{:?}", e),
+            Err(e) => log::error!("ドメインイベントの配信中にパニックが発生しました。{:?}", e),
+        }
+    }
+}
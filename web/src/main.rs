@@ -1,11 +1,48 @@
+use std::time::Duration;
+
 use actix_web::{web, App, HttpServer};
 use sqlx::postgres::PgPoolOptions;
+use time::UtcOffset;
 
 use controller::health_check::health_check;
+use controller::routes::audit::audit_router;
+use controller::routes::categories::category_router;
+use controller::routes::inventories::inventory_router;
+use controller::routes::payload_error_handler;
+use controller::routes::reports::report_router;
+use controller::routes::sales::sale_router;
 use controller::routes::vegetables::vegetable_router;
+use domain::models::tax::{RoundingMode, TaxInclusion, TaxPolicy};
+use infrastructure::postgres::interactors::audit::PgAuditLogInteractor;
+use infrastructure::postgres::interactors::category::PgCategoryInteractor;
+use infrastructure::postgres::interactors::inventory::PgInventoryInteractor;
+use infrastructure::postgres::interactors::report::PgReportInteractor;
+use infrastructure::postgres::interactors::sale::PgSaleInteractor;
 use infrastructure::postgres::interactors::vegetable::PgVegetableInteractor;
+use infrastructure::postgres::repositories::audit::PgAuditLogRepository;
+use infrastructure::postgres::repositories::category::PgCategoryRepository;
+use infrastructure::postgres::repositories::inventory::PgInventoryRepository;
+use infrastructure::postgres::repositories::outbox::PgOutboxRepository;
+use infrastructure::postgres::repositories::report::PgReportRepository;
+use infrastructure::postgres::repositories::unit_of_work::PgUnitOfWorkFactory;
+use infrastructure::postgres::repositories::vegetable::PgVegetableRepository;
+use usecase::events::EventDispatcher;
 use usecase::interactors::UsecaseInteractorContainer;
 
+use crate::events::{run_event_dispatcher, LoggingEventHandler};
+
+mod events;
+
+/// PostgreSQLを使用するユースケースインタラクターコンテナ
+type PgInteractorContainer = UsecaseInteractorContainer<
+    PgVegetableInteractor,
+    PgSaleInteractor,
+    PgReportInteractor,
+    PgInventoryInteractor,
+    PgCategoryInteractor,
+    PgAuditLogInteractor,
+>;
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     std::env::set_var("RUST_LOG", "debug");
@@ -16,17 +53,53 @@ async fn main() -> anyhow::Result<()> {
     let database_url = std::env::var("DATABASE_URL")?;
     let pool = PgPoolOptions::new().connect(&database_url).await?;
 
+    // 売上を集計する日と野菜を販売する月の境界は日本標準時とする
+    let offset = UtcOffset::from_hms(9, 0, 0)?;
+
+    // 販売に適用する消費税の計算方法を環境変数から取得して、指定されていない場合は
+    // 税込価格で消費税の端数を切り捨てる
+    let tax_policy = TaxPolicy {
+        inclusion: std::env::var("TAX_INCLUSION")
+            .ok()
+            .map(|value| TaxInclusion::try_from(value.as_str()))
+            .transpose()?
+            .unwrap_or_default(),
+        rounding: std::env::var("TAX_ROUNDING")
+            .ok()
+            .map(|value| RoundingMode::try_from(value.as_str()))
+            .transpose()?
+            .unwrap_or_default(),
+    };
+
     // ユースケースインタラクターコンテナを構築
-    let usecase_interactors = UsecaseInteractorContainer {
-        vegetable: PgVegetableInteractor::new(pool.clone()),
+    let usecase_interactors: PgInteractorContainer = UsecaseInteractorContainer {
+        vegetable: PgVegetableInteractor::new(PgVegetableRepository::new(pool.clone()), offset),
+        sale: PgSaleInteractor::new(PgUnitOfWorkFactory::new(pool.clone()), tax_policy, offset),
+        report: PgReportInteractor::new(PgReportRepository::new(pool.clone()), offset),
+        inventory: PgInventoryInteractor::new(PgInventoryRepository::new(pool.clone())),
+        category: PgCategoryInteractor::new(PgCategoryRepository::new(pool.clone())),
+        audit: PgAuditLogInteractor::new(PgAuditLogRepository::new(pool.clone())),
     };
 
+    // アウトボックスに記録されたドメインイベントを、1秒ごとにドメインイベントハンドラーに配信
+    let dispatcher =
+        EventDispatcher::new(PgOutboxRepository::new(pool.clone())).register(LoggingEventHandler);
+    tokio::spawn(run_event_dispatcher(dispatcher, Duration::from_secs(1)));
+
     // Webアプリケーションサーバを起動
     HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(usecase_interactors.clone()))
+            .app_data(web::JsonConfig::default().error_handler(payload_error_handler))
+            .app_data(web::QueryConfig::default().error_handler(payload_error_handler))
+            .app_data(web::PathConfig::default().error_handler(payload_error_handler))
             .service(health_check)
-            .service(vegetable_router::<PgVegetableInteractor>())
+            .service(vegetable_router::<PgInteractorContainer>())
+            .service(sale_router::<PgInteractorContainer>())
+            .service(category_router::<PgInteractorContainer>())
+            .service(inventory_router::<PgInteractorContainer>())
+            .service(report_router::<PgInteractorContainer>())
+            .service(audit_router::<PgInteractorContainer>())
     })
     .bind(("127.0.0.1", 8001))?
     .run()